@@ -0,0 +1,17 @@
+use std::{env, path::PathBuf, time::Instant};
+
+use aoc_common::{alloc, input, solve_timed, trace, Solution};
+use y2022_day_01::Day01;
+
+#[tracing::instrument]
+fn main() {
+    let _trace = trace::init_from_env().expect("should set up tracing");
+    let now = Instant::now();
+    let path = env::args_os().nth(1).map(PathBuf::from);
+    let input = input::read(Day01::YEAR, Day01::DAY, path).expect("should find input");
+    let (answer, timings) = solve_timed::<Day01>(input.as_str(), 2).expect("should be a string");
+    println!("{:?}", answer);
+    let elapsed = now.elapsed();
+    println!("Elapsed : {:.2?}", elapsed);
+    alloc::report(timings.allocations);
+}
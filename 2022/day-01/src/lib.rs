@@ -0,0 +1,40 @@
+use aoc_common::{Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    /// Total calories carried by each elf, in input order.
+    type Parsed<'a> = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    #[tracing::instrument(skip(input))]
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        input
+            .split("\n\n")
+            .map(|elf_load| {
+                elf_load
+                    .lines()
+                    .map(|item| {
+                        item.parse::<u32>()
+                            .map_err(|error| Day01::parse_error(input, item, error).into())
+                    })
+                    .sum::<Result<u32>>()
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -1,40 +1,28 @@
-use std::{error::Error, fs, time::Instant};
-use tracing::info;
+use aoc_common::{Result, Solution};
 
-#[tracing::instrument(skip(input))]
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let result = input
-        .split("\n\n")
-        .map(|elf_load| {
-            elf_load
-                .lines()
-                .map(|item| item.parse::<u32>().expect("Should be a number"))
-                .sum::<u32>()
-        })
+use crate::Day01;
+
+#[tracing::instrument(skip(loads))]
+pub fn process(loads: &[u32]) -> Result<u32> {
+    let result = loads
+        .iter()
         .max()
-        .unwrap();
-    Ok(result.to_string())
+        .ok_or_else(|| Day01::error("there are no elves"))?;
+    Ok(*result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day01;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("input_test.txt").expect("should be string");
-        assert_eq!("24000", process(input.as_str())?);
+        assert_eq!(24000, process(&Day01::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
@@ -0,0 +1,40 @@
+use aoc_common::{Result, Solution};
+use nom::{
+    character::complete::{alpha1, line_ending, space1},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+fn parser(input: &str) -> IResult<&str, Vec<(&str, &str)>> {
+    separated_list1(line_ending, separated_pair(alpha1, space1, alpha1))(input)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    /// The two columns of the strategy guide; what the second one means
+    /// depends on the part.
+    type Parsed<'a> = Vec<(&'a str, &'a str)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let (_, games) = parser(input).map_err(|error| Day02::nom_error(input, error))?;
+        Ok(games)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
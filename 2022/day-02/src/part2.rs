@@ -1,33 +1,37 @@
-use nom::{
-    character::complete::{alpha1, line_ending, space1},
-    multi::separated_list1,
-    sequence::separated_pair,
-    IResult,
-};
-use std::{error::Error, fs, time::Instant};
-use tracing::info;
+use aoc_common::{AocError, Result, Solution};
 
-fn match_play_options(input: &str) -> i8 {
+use crate::Day02;
+
+fn match_play_options(input: &str) -> std::result::Result<i8, AocError> {
     match input {
-        "A" => 1,
-        "B" => 2,
-        "C" => 3,
-        _ => panic!("must alwasy be a valid play"),
+        "A" => Ok(1),
+        "B" => Ok(2),
+        "C" => Ok(3),
+        _ => Err(Day02::error(format!(
+            "{input:?} is not a play, one of A, B or C"
+        ))),
     }
 }
 
-fn play_for_desired_outcome(opp: &str, desired_outcome: &str) -> (i8, i8) {
+fn play_for_desired_outcome(
+    opp: &str,
+    desired_outcome: &str,
+) -> std::result::Result<(i8, i8), AocError> {
     let outcome = match desired_outcome {
         "X" => -1,
         "Y" => 0,
         "Z" => 1,
-        _ => panic!("must alwasy be a valid play"),
+        _ => {
+            return Err(Day02::error(format!(
+                "{desired_outcome:?} is not an outcome, one of X, Y or Z"
+            )))
+        }
     };
 
     let rotate_play = |current_play: i8| -> i8 { current_play % 3 + 1 };
 
     let player_move: i8;
-    let opp_move = match_play_options(opp);
+    let opp_move = match_play_options(opp)?;
     if outcome == 0 {
         player_move = opp_move;
     } else if outcome > 0 {
@@ -39,42 +43,37 @@ fn play_for_desired_outcome(opp: &str, desired_outcome: &str) -> (i8, i8) {
         player_move = rotate_play(rotate_play(opp_move));
     }
 
-    (opp_move, player_move)
-}
-
-fn parser(input: &str) -> IResult<&str, Vec<(&str, &str)>> {
-    separated_list1(line_ending, separated_pair(alpha1, space1, alpha1))(input)
+    Ok((opp_move, player_move))
 }
 
-fn game(play: (&str, &str)) -> (u32, u32) {
+fn game(play: (&str, &str)) -> std::result::Result<(u32, u32), AocError> {
     // r vs p >1 vs 2  > 1 % 3 vs 2-1 > 1 vs 1 > right wins
     // s vs p >3 vs 2  > 3 % 3 vs 2-1 > 0 vs 1 > right wins
     // r vs s > 1 vs 3 > 1 % 3 vs 3-1 > 1 vs 2 > lef wins
-    let play_vals = play_for_desired_outcome(play.0, play.1);
-    if play_vals.0 % 3 == (play_vals.1 - 1) {
+    let play_vals = play_for_desired_outcome(play.0, play.1)?;
+    Ok(if play_vals.0 % 3 == (play_vals.1 - 1) {
         (play_vals.0 as u32, (play_vals.1 + 6) as u32)
     } else if play_vals.0 == play_vals.1 {
         ((play_vals.0 + 3) as u32, (play_vals.1 + 3) as u32)
     } else {
         ((play_vals.0 + 6) as u32, play_vals.1 as u32)
-    }
+    })
 }
 
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let (_, games) = parser(input).expect("should be a valid parse");
+pub fn process(games: &[(&str, &str)]) -> Result<u32> {
     let result = games
         .iter()
-        .map(|play| {
-            let res = game(*play).1;
-            res
-        })
-        .sum::<u32>();
-    Ok(result.to_string())
+        .map(|play| game(*play).map(|scores| scores.1))
+        .sum::<std::result::Result<u32, _>>()?;
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day02;
+    use aoc_common::Solution;
+    use std::fs;
 
     use rstest::rstest;
 
@@ -88,25 +87,29 @@ mod tests {
     #[case("A", "Z", (1, 2))]
     #[case("B", "Z", (2, 3))]
     #[case("C", "Z", (3, 1))]
-    fn line_test(#[case] opp: &str, #[case] desired_outcome: &str, #[case] expected: (i8, i8)) {
-        assert_eq!(expected, play_for_desired_outcome(opp, desired_outcome))
+    fn line_test(
+        #[case] opp: &str,
+        #[case] desired_outcome: &str,
+        #[case] expected: (i8, i8),
+    ) -> Result<()> {
+        assert_eq!(expected, play_for_desired_outcome(opp, desired_outcome)?);
+        Ok(())
     }
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_invalid_play() {
+        let error = process(&[("A", "Y"), ("D", "Y")]).expect_err("D is not a play");
+        assert_eq!(
+            r#"2022 day 2: "D" is not a play, one of A, B or C"#,
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("input_test.txt").expect("should be string");
-        assert_eq!("12", process(input.as_str())?);
+        assert_eq!(12, process(&Day02::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
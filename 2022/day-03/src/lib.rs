@@ -0,0 +1,36 @@
+use aoc_common::{Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    /// One rucksack's contents per line.
+    type Parsed<'a> = Vec<&'a str>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        if let Some(position) = input.find(|c: char| !c.is_ascii_alphabetic() && c != '\n') {
+            return Err(Day03::parse_error(
+                input,
+                &input[position..],
+                "expected an item, a-z or A-Z",
+            )
+            .into());
+        }
+        Ok(input.lines().collect())
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -1,9 +1,11 @@
-use std::{char, error::Error, fs, time::Instant};
+use aoc_common::{Result, Solution};
 use tracing::info;
 
-fn parser(input: &str) -> Vec<(&str, &str)> {
-    input
-        .lines()
+use crate::Day03;
+
+fn parser<'a>(rucksacks: &[&'a str]) -> Vec<(&'a str, &'a str)> {
+    rucksacks
+        .iter()
         .map(|line| line.split_at(line.len() / 2))
         .collect::<Vec<(&str, &str)>>()
 }
@@ -19,39 +21,35 @@ fn get_priority(input: u32) -> u32 {
 }
 
 #[tracing::instrument]
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let rucksacks = parser(input);
+pub fn process(rucksacks: &[&str]) -> Result<u32> {
+    let rucksacks = parser(rucksacks);
 
     let result = rucksacks
         .iter()
         .map(|(c1, c2)| {
-            let common = c1.chars().find(|c| c2.contains(*c)).unwrap();
-            dbg!(get_priority(common as u32))
+            let common = c1
+                .chars()
+                .find(|c| c2.contains(*c))
+                .ok_or_else(|| Day03::error(format!("no item is in both halves of {c1}{c2}")))?;
+            Ok(get_priority(common as u32))
         })
-        .sum::<u32>();
+        .sum::<Result<u32>>()?;
 
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day03;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("test_input.txt").expect("should be string");
-        assert_eq!("157", process(input.as_str())?);
+        assert_eq!(157, process(&Day03::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
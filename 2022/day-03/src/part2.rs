@@ -1,13 +1,14 @@
+use aoc_common::{Result, Solution};
 use itertools::Itertools;
-use std::{error::Error, fs, time::Instant};
 use tracing::info;
 
-fn parser(input: &str) -> Vec<(&str, &str, &str)> {
-    input
-        .lines()
-        .chunks(3)
-        .into_iter()
-        .map(|c| c.collect_tuple::<(&str, &str, &str)>().unwrap())
+use crate::Day03;
+
+fn parser<'a>(rucksacks: &[&'a str]) -> Vec<(&'a str, &'a str, &'a str)> {
+    rucksacks
+        .iter()
+        .copied()
+        .tuples::<(&str, &str, &str)>()
         .collect::<Vec<(&str, &str, &str)>>()
 }
 
@@ -22,8 +23,8 @@ fn get_priority(input: u32) -> u32 {
 }
 
 #[tracing::instrument]
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let rucksacks = parser(input);
+pub fn process(rucksacks: &[&str]) -> Result<u32> {
+    let rucksacks = parser(rucksacks);
 
     let result = rucksacks
         .iter()
@@ -31,33 +32,26 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
             let common = e1
                 .chars()
                 .find(|c| e2.contains(*c) && e3.contains(*c))
-                .unwrap();
-            dbg!(get_priority(common as u32))
+                .ok_or_else(|| Day03::error(format!("{e1}, {e2} and {e3} share no badge")))?;
+            Ok(get_priority(common as u32))
         })
-        .sum::<u32>();
+        .sum::<Result<u32>>()?;
 
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day03;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("test_input.txt").expect("should be string");
-        assert_eq!("70", process(input.as_str())?);
+        assert_eq!(70, process(&Day03::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
@@ -0,0 +1,46 @@
+use aoc_common::{Result, Solution};
+use nom::{bytes::complete::tag, character::complete, sequence::separated_pair, IResult};
+
+pub mod part1;
+pub mod part2;
+
+/// The section ranges assigned to a pair of elves.
+pub type Pair = ((u32, u32), (u32, u32));
+
+fn parse_input(input: &str) -> Result<Vec<Pair>> {
+    let range_parse = |range| -> IResult<&str, (u32, u32)> {
+        separated_pair(complete::u32, tag("-"), complete::u32)(range)
+    };
+    input
+        .lines()
+        .map(|line| {
+            let (_, line_tuples) = separated_pair(range_parse, tag(","), range_parse)(line)
+                .map_err(|error| Day04::nom_error(input, error))?;
+            Ok(line_tuples)
+        })
+        .collect::<Result<Vec<Pair>>>()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    /// The pair of section ranges assigned to each pair of elves.
+    type Parsed<'a> = Vec<Pair>;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -1,4 +1,8 @@
-use ::std::{fs, time::Instant};
+use ::std::{env, path::PathBuf, time::Instant};
+use aoc_common::{
+    alloc::{self, Allocations},
+    input, trace,
+};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -61,7 +65,7 @@ fn crates(input: &str) -> IResult<&str, (Vec<Vec<&str>>, Vec<Move>)> {
     }
     for vec in crates_horizontal.iter().rev() {
         for (i, c) in vec.iter().enumerate() {
-            crates_vertical[i].push(c.clone())
+            crates_vertical[i].push(*c)
         }
     }
     let final_crates: Vec<Vec<&str>> = crates_vertical
@@ -118,6 +122,18 @@ pub fn process_part2(input: &str) -> String {
 
     result
 }
+#[tracing::instrument]
+fn main() {
+    let _trace = trace::init_from_env().expect("should set up tracing");
+    let now = Instant::now();
+    let path = env::args_os().nth(1).map(PathBuf::from);
+    let input = input::read(2022, 5, path).expect("should find input");
+    let allocations = Allocations::now();
+    println!("{:?}", process_part2(input.as_str()));
+    let elapsed = now.elapsed();
+    println!("Elapsed : {:.2?}", elapsed);
+    alloc::report(Allocations::since(allocations));
+}
 
 #[cfg(test)]
 mod tests {
@@ -145,12 +161,3 @@ move 1 from 1 to 2";
         assert_eq!(result, "MCD");
     }
 }
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process_part2(input.as_str()));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
@@ -0,0 +1,145 @@
+use aoc_common::{
+    span::{self, Span},
+    Result, Solution,
+};
+use nom::{
+    character::complete::{self, alpha1, line_ending},
+    combinator::{consumed, eof},
+    multi::many_till,
+    sequence::{delimited, terminated, tuple},
+    IResult, Parser, Slice,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+use std::collections::HashMap;
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct Moves {
+    quant: u8,
+    from: u8,
+    to: u8,
+}
+
+// move 1 from 2 to 1
+fn parse_moves(input: Span) -> span::IResult<Moves> {
+    let (input, (_, quant, _, from, _, to)) = tuple((
+        tag("move "),
+        complete::u8.context("a number of crates"),
+        tag(" from "),
+        complete::u8.context("a stack number"),
+        tag(" to "),
+        complete::u8.context("a stack number"),
+    ))(input)?;
+    Ok((input, Moves { quant, from, to }))
+}
+
+/// Takes the top crate off the stack a move starts from.
+fn pop<'a>(map: &mut Stacks<'a>, mov: &Moves) -> Result<&'a str> {
+    map.get_mut(&mov.from)
+        .and_then(|stack| stack.pop())
+        .ok_or_else(|| Day05::error(format!("stack {} runs out of crates", mov.from)).into())
+}
+
+fn box_alpha_parse(input: &str) -> IResult<&str, &str> {
+    delimited(tag("["), alpha1, tag("]"))(input)
+}
+
+/// The stacks of crates, keyed by stack number.
+type Stacks<'a> = HashMap<u8, Vec<&'a str>>;
+
+fn parse_data(input: &str) -> Result<(Stacks<'_>, Vec<Moves>)> {
+    let (map_input, moves_input) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Day05::parse_error(input, "", "expected a blank line after the stacks"))?;
+
+    let numbers = map_input
+        .lines()
+        .last()
+        .ok_or_else(|| Day05::parse_error(input, input, "expected the stacks"))?;
+    let mut map = numbers
+        .split_ascii_whitespace()
+        .map(|val| {
+            let number = val
+                .parse::<u8>()
+                .map_err(|error| Day05::parse_error(input, val, error))?;
+            Ok((number, Vec::new()))
+        })
+        .collect::<Result<HashMap<u8, Vec<&str>>>>()?;
+
+    for line in map_input.lines().rev().skip(1) {
+        let mut rest = line;
+        let mut i = 0;
+        while !rest.is_empty() {
+            // every crate takes up 3 columns and the space after it
+            let end = rest.len().min(4);
+            let b = rest
+                .get(..end)
+                .ok_or_else(|| Day05::parse_error(input, rest, "expected a crate"))?;
+            let b_trim = b.trim();
+
+            if !b_trim.is_empty() {
+                let (_, bx_char): (&str, &str) =
+                    box_alpha_parse(b_trim).map_err(|error| Day05::nom_error(input, error))?;
+                map.get_mut(&(i + 1))
+                    .ok_or_else(|| Day05::parse_error(input, b, "crate outside of any stack"))?
+                    .push(bx_char);
+            }
+            rest = &rest[end..];
+            i += 1;
+        }
+    }
+
+    // spanning the whole input, so the moves know which line they are on
+    let moves_input = Span::new(input).slice(input.len() - moves_input.len()..);
+    let (_, (moves, _)) = many_till(
+        terminated(
+            consumed(parse_moves),
+            line_ending.or(eof).context("the end of the move"),
+        ),
+        eof,
+    )(moves_input)
+    .map_err(|error| Day05::located_error(input, error))?;
+    let moves = moves
+        .into_iter()
+        .map(|(line, moves)| {
+            for stack in [moves.from, moves.to] {
+                if !map.contains_key(&stack) {
+                    return Err(Day05::parse_error(
+                        input,
+                        line.fragment(),
+                        format!("no stack {stack}"),
+                    )
+                    .into());
+                }
+            }
+            Ok(moves)
+        })
+        .collect::<Result<Vec<Moves>>>()?;
+    Ok((map, moves))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    /// The starting stacks, keyed by stack number, and the crane moves.
+    type Parsed<'a> = (HashMap<u8, Vec<&'a str>>, Vec<Moves>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_data(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_common::{Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    /// The datastream buffer, searched as-is.
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_common::{Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    /// The calibration document, one line per value.
+    type Parsed<'a> = Vec<&'a str>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_common::{Result, Solution};
+
+use crate::Day01;
+
+pub fn process(lines: &[&str]) -> Result<u32> {
+    let output = lines
+        .iter()
+        .map(|line| {
+            let mut it = line.chars().filter_map(|character| character.to_digit(10));
+
+            let first = it
+                .next()
+                .ok_or_else(|| Day01::error(format!("{line:?} has no digit")))?;
+
+            Ok(match it.next_back() {
+                Some(num) => first * 10 + num,
+                None => first * 10 + first,
+            })
+        })
+        .sum::<Result<u32>>()?;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_process() -> Result<()> {
+        let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        assert_eq!(142, process(&Day01::parse(input)?)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use aoc_common::{AocError, Result, Solution};
+
+use crate::Day01;
+
+pub fn process(lines: &[&str]) -> Result<u32> {
+    let output = lines
+        .iter()
+        .copied()
+        .map(process_line)
+        .sum::<std::result::Result<u32, _>>()?;
+    Ok(output)
+}
+
+fn process_line(line: &str) -> std::result::Result<u32, AocError> {
+    let mut it = line.char_indices().filter_map(|(index, character)| {
+        let reduced_line = &line[index..];
+        let result = if reduced_line.starts_with("one") {
+            '1'
+        } else if reduced_line.starts_with("two") {
+            '2'
+        } else if reduced_line.starts_with("three") {
+            '3'
+        } else if reduced_line.starts_with("four") {
+            '4'
+        } else if reduced_line.starts_with("five") {
+            '5'
+        } else if reduced_line.starts_with("six") {
+            '6'
+        } else if reduced_line.starts_with("seven") {
+            '7'
+        } else if reduced_line.starts_with("eight") {
+            '8'
+        } else if reduced_line.starts_with("nine") {
+            '9'
+        } else {
+            character
+        };
+
+        result.to_digit(10)
+    });
+    let first = it
+        .next()
+        .ok_or_else(|| Day01::error(format!("{line:?} has no digit")))?;
+
+    Ok(match it.next_back() {
+        Some(num) => first * 10 + num,
+        None => first * 10 + first,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_common::Solution;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("two1nine", "29")]
+    #[case("eightwothree", "83")]
+    #[case("abcone2threexyz", "13")]
+    #[case("xtwone3four", "24")]
+    #[case("4nineeightseven2", "42")]
+    #[case("zoneight234", "14")]
+    #[case("7pqrstsixteen", "76")]
+    fn line_test(#[case] line: &str, #[case] expected: u32) -> Result<()> {
+        assert_eq!(expected, process_line(line)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_digit() {
+        let error = process(&["two1nine", "abc"]).expect_err("abc has no digit");
+        assert_eq!(r#"2023 day 1: "abc" has no digit"#, error.to_string());
+    }
+
+    #[test]
+    fn test_process() -> Result<()> {
+        let input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        assert_eq!(281, process(&Day01::parse(input)?)?);
+        Ok(())
+    }
+}
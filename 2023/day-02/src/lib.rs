@@ -0,0 +1,89 @@
+use aoc_common::{
+    span::{IResult, Span},
+    Result, Solution,
+};
+use nom::branch::alt;
+use nom::character::complete::{self, line_ending};
+use nom::combinator::{cut, eof};
+use nom::multi::{many_till, separated_list1};
+use nom::sequence::{preceded, separated_pair, terminated};
+use nom::Parser;
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct Cube<'a> {
+    color: &'a str,
+    amount: u32,
+}
+
+#[derive(Debug)]
+pub struct Game<'a> {
+    id: u32,
+    rounds: Vec<Vec<Cube<'a>>>,
+}
+
+// 4 red
+fn cude(input: Span) -> IResult<Cube> {
+    let (input, (amount, color)) = separated_pair(
+        complete::u32.context("a number of cubes"),
+        tag(" "),
+        alt((tag("red"), tag("green"), tag("blue"))).context("red, green or blue"),
+    )(input)?;
+    Ok((
+        input,
+        Cube {
+            color: color.fragment(),
+            amount,
+        },
+    ))
+}
+
+// 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+fn round(input: Span) -> IResult<Vec<Cube>> {
+    // a cube that doesn't parse is an error, rather than the end of the round
+    let (input, cubes) = separated_list1(tag(", "), cut(cude))(input)?;
+    Ok((input, cubes))
+}
+
+// Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+fn game(input: Span) -> IResult<Game> {
+    let (input, id) = preceded(tag("Game "), complete::u32.context("a game id"))(input)?;
+    let (input, rounds) = preceded(tag(": "), separated_list1(tag("; "), cut(round)))(input)?;
+    Ok((input, Game { id, rounds }))
+}
+
+fn games(input: Span) -> IResult<Vec<Game>> {
+    let (input, (games, _)) = many_till(
+        terminated(game, line_ending.or(eof).context("the end of the game")),
+        eof,
+    )(input)?;
+    Ok((input, games))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Parsed<'a> = Vec<Game<'a>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let (_, games) =
+            games(Span::new(input)).map_err(|error| Day02::located_error(input, error))?;
+        Ok(games)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
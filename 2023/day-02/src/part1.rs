@@ -0,0 +1,56 @@
+use aoc_common::Result;
+use std::collections::BTreeMap;
+
+use crate::Game;
+
+impl<'a> Game<'a> {
+    fn valid_game(&self, map: &BTreeMap<&str, u32>) -> Option<u32> {
+        self.rounds
+            .iter()
+            .all(|round| {
+                round.iter().all(|shown_cube| {
+                    map.get(shown_cube.color)
+                        .is_some_and(|max| shown_cube.amount <= *max)
+                })
+            })
+            .then_some(self.id)
+    }
+}
+
+pub fn process(games: &[Game]) -> Result<u32> {
+    let map = BTreeMap::from([("red", 12), ("green", 13), ("blue", 14)]);
+    Ok(games
+        .iter()
+        .filter_map(|game| game.valid_game(&map))
+        .sum::<u32>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_invalid_cube() {
+        let error = Day02::parse("Game 1: 3 blue\nGame 2: 3 blue, 4 pink\n")
+            .expect_err("pink is not a colour");
+        assert_eq!(
+            "2023 day 2, line 2, column 19: expected red, green or blue
+  | Game 2: 3 blue, 4 pink
+  |                   ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_process() -> Result<()> {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(8, process(&Day02::parse(input)?)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use std::{env, path::PathBuf};
+
+use aoc_common::{alloc, input, solve_timed, trace, Solution};
+use y2023_day_03::Day03;
+
+fn main() {
+    let _trace = trace::init_from_env().expect("should set up tracing");
+    let path = env::args_os().nth(1).map(PathBuf::from);
+    let input = input::read(Day03::YEAR, Day03::DAY, path).expect("should find input");
+    let (answer, timings) = solve_timed::<Day03>(input.as_str(), 1).expect("should be a string");
+    println!("{}", answer);
+    alloc::report(timings.allocations);
+}
@@ -0,0 +1,74 @@
+use aoc_common::{Grid, Result, Solution};
+use glam::IVec2;
+use std::convert::Infallible;
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub enum Value {
+    Symbol(char),
+    Empty,
+    Number(u32),
+}
+
+/// Every cell of the engine schematic, plus the digits of each number grouped
+/// together in reading order.
+#[derive(Debug)]
+pub struct Schematic {
+    map: Grid<Value>,
+    numbers: Vec<Vec<(IVec2, u32)>>,
+}
+
+fn parse_schematic(input: &str) -> Result<Schematic> {
+    let map = Day03::grid(input, input, |character| {
+        Ok::<_, Infallible>(match character {
+            '.' => Value::Empty,
+            c => match c.to_digit(10) {
+                Some(num) => Value::Number(num),
+                None => Value::Symbol(c),
+            },
+        })
+    })?;
+
+    let mut numbers: Vec<Vec<(IVec2, u32)>> = vec![];
+    for (position, value) in map.iter() {
+        if let Value::Number(num) = value {
+            match numbers.last_mut() {
+                Some(last)
+                    if last.last().is_some_and(|(last_position, _)| {
+                        *last_position + IVec2::X == position
+                    }) =>
+                {
+                    last.push((position, *num));
+                }
+                _ => numbers.push(vec![(position, *num)]),
+            }
+        }
+    }
+
+    Ok(Schematic { map, numbers })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = Schematic;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_schematic(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -87,7 +87,6 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
             .filter(|num| !num_positions.contains(num))
             .collect();
 
-        // dbg!(pos_to_check.len(), pos_to_check);
         let is_part_number = pos_to_check.iter().any(|pos| {
             let value = map.get(pos);
             #[allow(clippy::match_like_matches_macro)]
@@ -0,0 +1,90 @@
+use aoc_common::{
+    span::{IResult, Span},
+    Result, Solution,
+};
+use nom::{
+    character::complete::{self, digit1, line_ending, space0, space1},
+    combinator::eof,
+    multi::{fold_many1, many_till},
+    sequence::{delimited, separated_pair, terminated, tuple},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+use std::collections::HashSet;
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct Card {
+    winning_numbers: HashSet<u32>,
+    my_numbers: HashSet<u32>,
+}
+
+impl Card {
+    fn matches(&self) -> u32 {
+        self.winning_numbers.intersection(&self.my_numbers).count() as u32
+    }
+}
+
+fn set(input: Span) -> IResult<HashSet<u32>> {
+    fold_many1(
+        terminated(complete::u32, space0),
+        HashSet::new,
+        |mut acc: HashSet<_>, item| {
+            acc.insert(item);
+            acc
+        },
+    )(input)
+}
+// Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+fn card(input: Span) -> IResult<Card> {
+    let (input, _) = delimited(
+        tuple((tag("Card"), space1)),
+        digit1.context("a card number"),
+        tuple((tag(":"), space1)),
+    )(input)?;
+    separated_pair(
+        set.context("the winning numbers"),
+        tuple((tag("|"), space1)),
+        set.context("your numbers"),
+    )
+    .map(|(winning_numbers, my_numbers)| Card {
+        winning_numbers,
+        my_numbers,
+    })
+    .parse(input)
+}
+
+fn cards(input: Span) -> IResult<Vec<Card>> {
+    let (input, (cards, _)) = many_till(
+        terminated(card, line_ending.or(eof).context("the end of the card")),
+        eof,
+    )(input)?;
+    Ok((input, cards))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Parsed<'a> = Vec<Card>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let (_, cards) =
+            cards(Span::new(input)).map_err(|error| Day04::located_error(input, error))?;
+        Ok(cards)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
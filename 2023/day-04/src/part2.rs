@@ -1,61 +1,12 @@
-use std::collections::{BTreeMap, HashSet};
-use std::error::Error;
-use std::fs;
+use aoc_common::Result;
+use std::collections::BTreeMap;
 
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, digit1, line_ending, space0, space1},
-    multi::{fold_many1, separated_list1},
-    sequence::{delimited, separated_pair, terminated, tuple},
-    IResult, Parser,
-};
+use crate::Card;
 
-#[derive(Debug)]
-struct Card {
-    winning_numbers: HashSet<u32>,
-    my_numbers: HashSet<u32>,
-}
-
-impl Card {
-    fn score(&self) -> u32 {
-        self.winning_numbers.intersection(&self.my_numbers).count() as u32
-    }
-}
-
-fn set(input: &str) -> IResult<&str, HashSet<u32>> {
-    fold_many1(
-        terminated(complete::u32, space0),
-        HashSet::new,
-        |mut acc: HashSet<_>, item| {
-            acc.insert(item);
-            acc
-        },
-    )(input)
-}
-// Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-fn card(input: &str) -> IResult<&str, Card> {
-    let (input, _) = delimited(
-        tuple((tag("Card"), space1)),
-        digit1,
-        tuple((tag(":"), space1)),
-    )(input)?;
-    separated_pair(set, tuple((tag("|"), space1)), set)
-        .map(|(winning_numbers, my_numbers)| Card {
-            winning_numbers,
-            my_numbers,
-        })
-        .parse(input)
-}
-
-fn cards(input: &str) -> IResult<&str, Vec<Card>> {
-    separated_list1(line_ending, card)(input)
-}
-
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let (_, card_data) = cards(input).expect("a valid parse");
+pub fn process(card_data: &[Card]) -> Result<u32> {
     let data = card_data
         .iter()
-        .map(|card| card.score())
+        .map(|card| card.matches())
         .collect::<Vec<_>>();
     let store = (0..card_data.len())
         .map(|index| (index, 1))
@@ -65,7 +16,7 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
         .iter()
         .enumerate()
         .fold(store, |mut acc, (index, card_score)| {
-            let to_add = *acc.get(&index).unwrap();
+            let to_add = acc[&index];
             for i in (index + 1)..(index + 1 + *card_score as usize) {
                 acc.entry(i).and_modify(|value| {
                     *value += to_add;
@@ -89,12 +40,15 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
     //     })
     //     .values()
     //     .sum();
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day04;
+    use aoc_common::Solution;
+    use std::fs;
 
     // use rstest::rstest;
     //
@@ -111,15 +65,9 @@ mod tests {
     //     assert_eq!(expected, card.score());
     // }
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
+    fn test_process() -> Result<()> {
         let input = fs::read_to_string("input_test.txt").expect("should be string");
-        assert_eq!("30", process(input.as_str())?);
+        assert_eq!(30, process(&Day04::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-fn main() {
-    let input = fs::read_to_string("input1.txt").expect("should be string");
-    // println!("{}", input.as_str())
-    println!("{:?}", process(input.as_str()).expect("should be a string"))
-}
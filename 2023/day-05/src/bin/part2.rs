@@ -0,0 +1,14 @@
+use std::{env, path::PathBuf};
+
+use aoc_common::{alloc, input, solve_timed, trace, Solution};
+use y2023_day_05::Day05;
+
+#[tracing::instrument]
+fn main() {
+    let _trace = trace::init_from_env().expect("should set up tracing");
+    let path = env::args_os().nth(1).map(PathBuf::from);
+    let input = input::read(Day05::YEAR, Day05::DAY, path).expect("should find input");
+    let (answer, timings) = solve_timed::<Day05>(input.as_str(), 2).expect("should be a string");
+    println!("{:?}", answer);
+    alloc::report(timings.allocations);
+}
@@ -1,6 +1,11 @@
 #![allow(dead_code)] // want to keep prior solutions around
 
-use std::{num::ParseIntError, ops::Range, str::FromStr};
+use std::{env, num::ParseIntError, ops::Range, path::PathBuf, str::FromStr};
+
+use aoc_common::{
+    alloc::{self, Allocations},
+    input, trace,
+};
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
 struct RangeMap {
@@ -284,35 +289,22 @@ fn solve_day_05(input: &str) -> (usize, usize) {
 }
 
 fn main() {
-    let input = include_str!("../input1.txt");
+    let _trace = trace::init_from_env().expect("should set up tracing");
+    let path = env::args_os().nth(1).map(PathBuf::from);
+    let input = input::read(2023, 5, path).expect("should find input");
+    let allocations = Allocations::now();
 
-    let (part_1, part_2) = solve_day_05(input);
+    let (part_1, part_2) = solve_day_05(&input);
 
     println!("Lowest reachable location: {}", part_1);
 
     println!("Lowest reachable location (part 2): {}", part_2);
+    alloc::report(Allocations::since(allocations));
 }
 
 #[test]
 fn example() {
-    let (part_1, part_2) = solve_day_05("../input1.txt");
+    let (part_1, part_2) = solve_day_05(include_str!("../../input_test.txt"));
     assert_eq!(part_1, 35);
     assert_eq!(part_2, 46);
 }
-// let (times, distances) = input.split_once("\n").expect("should ne valid split");
-// let times = times
-//     .strip_prefix("Time:")
-//     .expect("should exist")
-//     .trim()
-//     .split_ascii_whitespace()
-//     .map(|time| time.parse::<u32>())
-//     .collect::<Result<Vec<u32>, ParseIntError>>();
-// let distances = distances
-//     .strip_prefix("Distance:")
-//     .expect("should exist")
-//     .trim()
-//     .replace(" ", "")
-//     .parse::<u64>()
-//     .expect("shoud be a number");
-// info!(times);
-// info!(distances);
@@ -0,0 +1,114 @@
+use aoc_common::{
+    span::{IResult, Span},
+    Result, Solution,
+};
+use nom::character::complete::{alpha1, multispace0, multispace1, space1};
+use nom::combinator::{cut, eof};
+use nom::sequence::tuple;
+use nom::{
+    character::complete::{self, line_ending},
+    multi::separated_list1,
+    multi::{many1, many_till},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+use std::ops::Range;
+use tracing::info;
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct SeedMap {
+    mappings: Vec<(Range<u64>, Range<u64>)>,
+}
+impl SeedMap {
+    fn translate(&self, source: u64) -> u64 {
+        let valid_mapping = self
+            .mappings
+            .iter()
+            .find(|(source_range, _)| source_range.contains(&source));
+        let Some((source_range, destination_range)) = valid_mapping else {
+            return source;
+        };
+
+        let offset = source - source_range.start;
+        destination_range.start + offset
+    }
+}
+
+fn line(input: Span) -> IResult<(Range<u64>, Range<u64>)> {
+    // past its first number a line has to be a whole mapping, rather than
+    // the end of the map
+    let (input, (destination, source, num)) = tuple((
+        complete::u64,
+        cut(complete::u64
+            .context("a source range start")
+            .preceded_by(tag(" "))),
+        cut(complete::u64
+            .context("a range length")
+            .preceded_by(tag(" "))),
+    ))(input)?;
+
+    Ok((
+        input,
+        (source..(source + num), destination..(destination + num)),
+    ))
+}
+
+// seed-to-soil map:
+fn seed_map(input: Span) -> IResult<SeedMap> {
+    multispace1
+        .precedes(tuple((alpha1, tag("-to-"), alpha1, tag(" map:"))).context("a map"))
+        .precedes(many1(line_ending.precedes(line)).map(|mappings| SeedMap { mappings }))
+        .parse(input)
+}
+
+/// The `seeds:` line followed by each category map, in conversion order.
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<SeedMap>,
+}
+
+impl Almanac {
+    fn location(&self, seed: u64) -> u64 {
+        self.maps.iter().fold(seed, |seed, map| map.translate(seed))
+    }
+}
+
+#[tracing::instrument(skip(input), fields(input_first_line = input.lines().next().unwrap_or_default()))]
+fn parse_seedmaps(input: Span) -> IResult<Almanac> {
+    let (input, seeds) = tag("seeds: ")
+        .precedes(separated_list1(space1, complete::u64.context("a seed")))
+        .terminated(line_ending.context("the end of the seeds"))
+        .parse(input)?;
+    info!(?seeds);
+    let (input, (maps, _)) = many_till(seed_map, multispace0.precedes(eof))(input)?;
+    Ok((input, Almanac { seeds, maps }))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = Almanac;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let (_, almanac) =
+            parse_seedmaps(Span::new(input)).map_err(|error| Day05::located_error(input, error))?;
+        Ok(almanac)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
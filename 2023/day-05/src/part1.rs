@@ -1,92 +1,36 @@
-use std::fs;
-use std::{error::Error, ops::Range};
+use aoc_common::{Result, Solution};
 
-use nom::character::complete::multispace1;
-use nom::sequence::tuple;
-use nom::{
-    bytes::complete::take_until,
-    character::complete::{self, line_ending},
-    multi::many1,
-    multi::separated_list1,
-    IResult, Parser,
-};
-use nom_supreme::{tag::complete::tag, ParserExt};
+use crate::{Almanac, Day05};
 
-#[derive(Debug)]
-struct SeedMap {
-    mappings: Vec<(Range<u64>, Range<u64>)>,
-}
-impl SeedMap {
-    fn translate(&self, source: u64) -> u64 {
-        let valid_mapping = self
-            .mappings
-            .iter()
-            .find(|(source_range, _)| source_range.contains(&source));
-        let Some((source_range, destination_range)) = valid_mapping else {
-            return source;
-        };
-
-        let offset = source - source_range.start;
-        destination_range.start + offset
-    }
-}
-
-fn line(input: &str) -> IResult<&str, (Range<u64>, Range<u64>)> {
-    let (input, (destination, source, num)) = tuple((
-        complete::u64,
-        complete::u64.preceded_by(tag(" ")),
-        complete::u64.preceded_by(tag(" ")),
-    ))(input)?;
-
-    Ok((
-        input,
-        (source..(source + num), destination..(destination + num)),
-    ))
-}
-
-fn seed_map(input: &str) -> IResult<&str, SeedMap> {
-    take_until("map:")
-        .precedes(tag("map:"))
-        .precedes(many1(line_ending.precedes(line)).map(|mappings| SeedMap { mappings }))
-        .parse(input)
-}
-
-fn parse_seedmaps(input: &str) -> IResult<&str, (Vec<u64>, Vec<SeedMap>)> {
-    let (input, seeds) = tag("seeds: ")
-        .precedes(separated_list1(multispace1, complete::u64))
-        .parse(input)?;
-    let (input, maps) = many1(seed_map)(input)?;
-    Ok((input, (seeds, maps)))
-}
-
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let (_, (seeds, maps)) = parse_seedmaps(input).expect("a valid parse");
-    let locations = seeds
+pub fn process(almanac: &Almanac) -> Result<u64> {
+    let locations = almanac
+        .seeds
         .iter()
-        .map(|seed| maps.iter().fold(*seed, |seed, map| map.translate(seed)))
+        .map(|seed| almanac.location(*seed))
         .collect::<Vec<u64>>();
 
-    Ok(locations.iter().min().expect("should exist").to_string())
+    let result = locations
+        .iter()
+        .min()
+        .ok_or_else(|| Day05::error("there are no seeds"))?;
+    Ok(*result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day05;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
+    fn test_process() -> Result<()> {
         let input = fs::read_to_string("input_test.txt").expect("should be string");
-        assert_eq!("35", process(input.as_str())?);
+        assert_eq!(35, process(&Day05::parse(input.as_str())?)?);
         Ok(())
     }
 }
 
-fn main() {
-    let input = fs::read_to_string("input_test.txt").expect("should be string");
-    // println!("{}", input.as_str())
-    println!("{:?}", process(input.as_str()).expect("should be a string"))
-}
-
 // #[derive(Debug)]
 // struct Card {
 // }
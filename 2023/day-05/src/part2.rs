@@ -1,107 +1,51 @@
-use std::fs;
-use std::{error::Error, ops::Range};
-
-use nom::character::complete::multispace1;
-use nom::sequence::{separated_pair, tuple};
-use nom::{
-    bytes::complete::take_until,
-    character::complete::{self, line_ending},
-    multi::many1,
-    multi::separated_list1,
-    IResult, Parser,
-};
-use nom_supreme::{tag::complete::tag, ParserExt};
+use aoc_common::{Result, Solution};
+use std::ops::Range;
 use tracing::info;
 
-#[derive(Debug)]
-struct SeedMap {
-    mappings: Vec<(Range<u64>, Range<u64>)>,
-}
-impl SeedMap {
-    fn translate(&self, source: u64) -> u64 {
-        let valid_mapping = self
-            .mappings
-            .iter()
-            .find(|(source_range, _)| source_range.contains(&source));
-        let Some((source_range, destination_range)) = valid_mapping else {
-            return source;
-        };
-
-        let offset = source - source_range.start;
-        destination_range.start + offset
-    }
-}
-
-fn line(input: &str) -> IResult<&str, (Range<u64>, Range<u64>)> {
-    let (input, (destination, source, num)) = tuple((
-        complete::u64,
-        complete::u64.preceded_by(tag(" ")),
-        complete::u64.preceded_by(tag(" ")),
-    ))(input)?;
-
-    Ok((
-        input,
-        (source..(source + num), destination..(destination + num)),
-    ))
-}
-
-fn seed_map(input: &str) -> IResult<&str, SeedMap> {
-    take_until("map:")
-        .precedes(tag("map:"))
-        .precedes(many1(line_ending.precedes(line)).map(|mappings| SeedMap { mappings }))
-        .parse(input)
-}
+use crate::{Almanac, Day05};
 
-#[tracing::instrument(skip(input), fields(input_first_line = input.split("\n").next().unwrap()))]
-fn parse_seedmaps(input: &str) -> IResult<&str, (Vec<Range<u64>>, Vec<SeedMap>)> {
-    let (input, seeds) = tag("seeds: ")
-        .precedes(separated_list1(
-            multispace1,
-            separated_pair(complete::u64, tag(" "), complete::u64)
-                .map(|(start, offset)| start..(start + offset)),
-        ))
-        .parse(input)?;
+pub fn process(almanac: &Almanac) -> Result<u64> {
+    let seeds = almanac
+        .seeds
+        .chunks(2)
+        .map(|pair| match pair {
+            [start, length] => Ok(*start..(start + length)),
+            _ => Err(Day05::error(format!(
+                "seed {} has no range length",
+                pair[0]
+            ))),
+        })
+        .collect::<std::result::Result<Vec<Range<u64>>, _>>()?;
     info!(?seeds);
-    let (input, maps) = many1(seed_map)(input)?;
-    Ok((input, (seeds, maps)))
-}
-
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let (_, (seeds, maps)) = parse_seedmaps(input).expect("a valid parse");
     // .progress()
     let locations = seeds
         .iter()
         // .progress()
         .flat_map(|range| range.clone())
-        .map(|seed| maps.iter().fold(seed, |seed, map| map.translate(seed)))
+        .map(|seed| almanac.location(seed))
         .min();
 
-    Ok(locations.iter().min().expect("should exist").to_string())
+    let result = locations.ok_or_else(|| Day05::error("there are no seeds"))?;
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day05;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         info!("here");
         let input = fs::read_to_string("input_test.txt").expect("should be string");
-        assert_eq!("46", process(input.as_str())?);
+        assert_eq!(46, process(&Day05::parse(input.as_str())?)?);
         Ok(())
     }
 }
 
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let input = fs::read_to_string("input_test.txt").expect("should be string");
-    // println!("{}", input.as_str())
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    info!("test");
-}
-
 // #[derive(Debug)]
 // struct Card {
 // }
@@ -0,0 +1,67 @@
+use aoc_common::{Result, Solution};
+use std::cmp::Ordering;
+
+pub mod part1;
+pub mod part2;
+
+/// The number columns of the `Time:` and `Distance:` lines, left as text
+/// because part 2 reads them with the spaces removed.
+#[derive(Debug)]
+pub struct Races<'a> {
+    times: Vec<&'a str>,
+    distances: Vec<&'a str>,
+}
+
+#[tracing::instrument(skip(input))]
+fn parse_races(input: &str) -> Result<Races<'_>> {
+    let mut lines = input.lines();
+    let mut numbers = |label: &str| -> Result<Vec<&str>> {
+        let line = lines.next().unwrap_or_default();
+        let numbers = line
+            .strip_prefix(label)
+            .ok_or_else(|| Day06::parse_error(input, line, format!("expected {label}")))?
+            .split_ascii_whitespace()
+            .collect::<Vec<_>>();
+        if let Some(number) = numbers
+            .iter()
+            .find(|number| !number.bytes().all(|c| c.is_ascii_digit()))
+        {
+            return Err(Day06::parse_error(input, number, "expected a number").into());
+        }
+        Ok(numbers)
+    };
+    let times = numbers("Time:")?;
+    let distances = numbers("Distance:")?;
+    let extra = match times.len().cmp(&distances.len()) {
+        Ordering::Greater => Some((times[distances.len()], "no distance for this time")),
+        Ordering::Less => Some((distances[times.len()], "no time for this distance")),
+        Ordering::Equal => None,
+    };
+    if let Some((number, cause)) = extra {
+        return Err(Day06::parse_error(input, number, cause).into());
+    }
+    Ok(Races { times, distances })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Parsed<'a> = Races<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_races(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
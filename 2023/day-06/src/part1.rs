@@ -1,40 +1,36 @@
-use std::{error::Error, fs, time::Instant};
+use aoc_common::{Result, Solution};
 use tracing::info;
 
-#[tracing::instrument(skip(input))]
-fn parse_times(input: &str) -> (Vec<u32>, Vec<u32>) {
-    let (times, distances) = input.split_once("\n").expect("should ne valid split");
-    let times = times
-        .strip_prefix("Time:")
-        .expect("should exist")
-        .trim()
-        .split_ascii_whitespace()
+use crate::{Day06, Races};
+
+#[tracing::instrument(skip(races))]
+fn parse_times(races: &Races) -> Result<(Vec<u32>, Vec<u32>)> {
+    let times = races
+        .times
+        .iter()
         .map(|time| time.parse::<u32>())
-        .collect::<Result<Vec<u32>, _>>()
-        .expect("should be list of numbers");
-    let distances = distances
-        .strip_prefix("Distance:")
-        .expect("should exist")
-        .trim()
-        .split_ascii_whitespace()
+        .collect::<std::result::Result<Vec<u32>, _>>()
+        .map_err(|error| Day06::error(format!("times: {error}")))?;
+    let distances = races
+        .distances
+        .iter()
         .map(|dist| dist.parse::<u32>())
-        .collect::<Result<Vec<u32>, _>>()
-        .expect("should be list of numbers");
+        .collect::<std::result::Result<Vec<u32>, _>>()
+        .map_err(|error| Day06::error(format!("distances: {error}")))?;
     info!(?times);
     info!(?distances);
 
-    (times, distances)
+    Ok((times, distances))
 }
 
-#[tracing::instrument(skip(input))]
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let (times, distances) = parse_times(input);
+#[tracing::instrument(skip(races))]
+pub fn process(races: &Races) -> Result<usize> {
+    let (times, distances) = parse_times(races)?;
     let result = times
         .into_iter()
         .zip(distances)
         .map(|(time, record_dist)| {
             (0..time)
-                .into_iter()
                 .filter_map(|speed| {
                     let traveled_dist = (time - speed) * speed;
                     (traveled_dist > record_dist).then_some(traveled_dist)
@@ -42,29 +38,36 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
                 .count()
         })
         .product::<usize>();
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day06;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("input_test.txt").expect("should be string");
-        assert_eq!("288", process(input.as_str())?);
+        assert_eq!(288, process(&Day06::parse(input.as_str())?)?);
         Ok(())
     }
-}
 
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input1.txt").expect("should be string");
-    info!(input);
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
+    #[test]
+    fn test_uneven_rows() {
+        let error =
+            Day06::parse("Time:  7  15  30\nDistance:  9  40\n").expect_err("should not parse");
+        assert_eq!(
+            "2023 day 6, line 1, column 15: no distance for this time\n  | Time:  7  15  30\n  |               ^",
+            error.to_string()
+        );
+        let error = Day06::parse("Time:  7\nDistance:  9  40\n").expect_err("should not parse");
+        assert_eq!(
+            "2023 day 6, line 2, column 15: no time for this distance\n  | Distance:  9  40\n  |               ^",
+            error.to_string()
+        );
+    }
 }
@@ -1,59 +1,46 @@
-use std::{error::Error, fs, time::Instant};
-use tracing::info;
+use aoc_common::{Result, Solution};
 
-#[tracing::instrument(skip(input))]
-fn parse_times(input: &str) -> (u64, u64) {
-    let (time, distance) = input.split_once("\n").expect("should ne valid split");
-    let time = time
-        .strip_prefix("Time:")
-        .expect("should exist")
-        .trim()
-        .replace(" ", "")
+use crate::{Day06, Races};
+
+#[tracing::instrument(skip(races))]
+fn parse_times(races: &Races) -> Result<(u64, u64)> {
+    let time = races
+        .times
+        .concat()
         .parse::<u64>()
-        .expect("shoud be a number");
-    let distance = distance
-        .strip_prefix("Distance:")
-        .expect("should exist")
-        .trim()
-        .replace(" ", "")
+        .map_err(|error| Day06::error(format!("time: {error}")))?;
+    let distance = races
+        .distances
+        .concat()
         .parse::<u64>()
-        .expect("shoud be a number");
-    (time, distance)
+        .map_err(|error| Day06::error(format!("distance: {error}")))?;
+    Ok((time, distance))
 }
 
-#[tracing::instrument(skip(input))]
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let (time, record_dist) = parse_times(input);
+#[tracing::instrument(skip(races))]
+pub fn process(races: &Races) -> Result<usize> {
+    let (time, record_dist) = parse_times(races)?;
     let result = (0..time)
-        .into_iter()
         .filter_map(|speed| {
             let traveled_dist = (time - speed) * speed;
             (traveled_dist > record_dist).then_some(traveled_dist)
         })
         .count();
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day06;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("input_test.txt").expect("should be string");
-        assert_eq!("71503", process(input.as_str())?);
+        assert_eq!(71503, process(&Day06::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input1.txt").expect("should be string");
-    info!(input);
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
@@ -1,5 +1,9 @@
-use std::{error::Error, fs, ops::Deref, time::Instant};
-use tracing::info;
+use std::{env, error::Error, ops::Deref, path::PathBuf, time::Instant};
+
+use aoc_common::{
+    alloc::{self, Allocations},
+    input, trace,
+};
 
 use itertools::{Itertools, Position};
 
@@ -14,11 +18,6 @@ enum HandType {
     HighCard = 0,
 }
 
-#[derive(Debug)]
-struct Hand {
-    hand: HandType,
-    first_card: char,
-}
 fn score_hand(hand: &str) -> (HandType, (u32, u32, u32, u32, u32)) {
     use HandType::*;
 
@@ -82,27 +81,30 @@ pub fn process(input: &str) -> Result<String, Box<dyn Error>> {
         .sum::<u32>();
     Ok(hands.to_string())
 }
+
+#[tracing::instrument]
+fn main() {
+    let _trace = trace::init_from_env().expect("should set up tracing");
+    let now = Instant::now();
+    let path = env::args_os().nth(1).map(PathBuf::from);
+    let input = input::read(2023, 7, path).expect("should find input");
+    let allocations = Allocations::now();
+    println!("{:?}", process(input.as_str()).expect("should be a string"));
+    let elapsed = now.elapsed();
+    println!("Elapsed : {:.2?}", elapsed);
+    alloc::report(Allocations::since(allocations));
+}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     #[tracing::instrument]
     #[test]
     fn test_process() -> Result<(), Box<dyn Error>> {
         tracing_subscriber::fmt::init();
         let input = fs::read_to_string("input_test.txt").expect("should be string");
-        assert_eq!("6440", process(input.as_str())?);
+        assert_eq!("5905", process(input.as_str())?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input1.txt").expect("should be string");
-    // info!(input);
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
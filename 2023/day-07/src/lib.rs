@@ -0,0 +1,50 @@
+use aoc_common::{Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Each hand's five cards and its bid.
+#[tracing::instrument(skip(input))]
+fn parse_hands(input: &str) -> Result<Vec<(&str, u32)>> {
+    input
+        .trim()
+        .split("\n")
+        .map(|hand| {
+            let (cards, bid) = hand
+                .split_once(" ")
+                .ok_or_else(|| Day07::parse_error(input, hand, "expected cards and a bid"))?;
+            if cards.len() != 5 || !cards.chars().all(|card| "23456789TJQKA".contains(card)) {
+                return Err(
+                    Day07::parse_error(input, cards, "expected five cards, 2-9 or TJQKA").into(),
+                );
+            }
+            let bid = bid
+                .parse::<u32>()
+                .map_err(|error| Day07::parse_error(input, bid, error))?;
+            Ok((cards, bid))
+        })
+        .collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Parsed<'a> = Vec<(&'a str, u32)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_hands(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -1,16 +1,18 @@
+use aoc_common::{AocError, Result, Solution};
 use itertools::{Itertools, Position};
-use std::{error::Error, fs, ops::Deref, time::Instant};
+use std::ops::Deref;
+
+use crate::Day07;
 use tracing::info;
 
 #[derive(Debug)]
-struct Hand<'a> {
-    cards: &'a str,
+struct Hand {
     bid: u32,
     rank: (u32, (u32, u32, u32, u32, u32)),
 }
 
-impl<'a> Hand<'a> {
-    fn init(cards: &str, bid: u32) -> Hand {
+impl Hand {
+    fn init(cards: &str, bid: u32) -> std::result::Result<Hand, AocError> {
         let counts = cards.chars().counts();
         let values = if let Some(joker_count) = counts.get(&'J') {
             if *joker_count == 5 {
@@ -30,8 +32,6 @@ impl<'a> Hand<'a> {
         } else {
             counts.values().sorted().join("")
         };
-        // info!(?counts);
-        // info!(?values);
         let hand_type = match values.deref() {
             "5" => 6,
             "14" => 5,
@@ -40,38 +40,35 @@ impl<'a> Hand<'a> {
             "122" => 2,
             "1112" => 1,
             "11111" => 0,
-            _ => panic!("hand should be valid"),
+            _ => return Err(Day07::error(format!("{cards} is not a hand of five cards"))),
         };
         let high_card = cards
             .chars()
             .map(|card| match card {
-                'A' => 14,
-                'K' => 13,
-                'Q' => 12,
-                'J' => 0,
-                'T' => 10,
-                value => value.to_digit(10).unwrap(),
+                'A' => Some(14),
+                'K' => Some(13),
+                'Q' => Some(12),
+                'J' => Some(0),
+                'T' => Some(10),
+                value => value.to_digit(10),
             })
-            .collect_tuple()
-            .unwrap();
+            .collect::<Option<Vec<u32>>>()
+            .and_then(|values| values.into_iter().collect_tuple())
+            .ok_or_else(|| Day07::error(format!("{cards} is not a hand of five cards")))?;
 
         let rank = (hand_type, high_card);
 
-        Hand { cards, bid, rank }
+        Ok(Hand { bid, rank })
     }
 }
 
-#[tracing::instrument(skip(input))]
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let result = input
-        .trim()
-        .split("\n")
-        .map(|hand| {
-            let (cards, bid) = hand.split_once(" ").expect("this should be a valid split");
-            let bid = bid.parse::<u32>().expect("should be a number");
-            Hand::init(cards, bid)
-            // (hand, bid.parse::<u32>().expect("should be a number"))
-        })
+#[tracing::instrument(skip(hands))]
+pub fn process(hands: &[(&str, u32)]) -> Result<u32> {
+    let result = hands
+        .iter()
+        .map(|(cards, bid)| Hand::init(cards, *bid))
+        .collect::<std::result::Result<Vec<_>, _>>()?
+        .into_iter()
         .sorted_by_key(|h| h.rank)
         .enumerate()
         .map(|(index, hand)| {
@@ -81,30 +78,29 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
         })
         .sum::<u32>();
 
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day07;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[tracing::instrument]
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
-        let input = fs::read_to_string("input.txt").expect("should be string");
-        assert_eq!("252052080", process(input.as_str())?);
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let input = fs::read_to_string("input_test.txt").expect("should be string");
+        assert_eq!(5905, process(&Day07::parse(input.as_str())?)?);
         Ok(())
     }
-}
 
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    // info!(input);
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
+    #[test]
+    fn test_input() -> Result<()> {
+        let input = fs::read_to_string("input.txt").expect("should be string");
+        assert_eq!(252898370, process(&Day07::parse(input.as_str())?)?);
+        Ok(())
+    }
 }
@@ -0,0 +1,88 @@
+use aoc_common::{
+    span::{IResult, Span},
+    Result, Solution,
+};
+use nom::{
+    branch::alt,
+    character::complete::{self, alphanumeric1, line_ending, multispace1},
+    combinator::{cut, eof},
+    multi::{many1, many_till},
+    sequence::{delimited, separated_pair, terminated},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+use std::collections::BTreeMap;
+
+pub mod part1;
+pub mod part2;
+
+/// Each node mapped to its left and right neighbours.
+pub type Network<'a> = BTreeMap<&'a str, (&'a str, &'a str)>;
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[tracing::instrument(skip(input))]
+fn parser(input: Span) -> IResult<(Vec<Direction>, Network)> {
+    let (input, instructions) = many1(alt((
+        complete::char('R').map(|_| Direction::Right),
+        complete::char('L').map(|_| Direction::Left),
+    )))
+    .context("instructions, each R or L")
+    .parse(input)?;
+
+    let (input, _) = multispace1.context("a blank line").parse(input)?;
+    let node = |input| {
+        alphanumeric1
+            .map(|node: Span<'_>| *node.fragment())
+            .context("a node")
+            .parse(input)
+    };
+    // AAA = (BBB, CCC)
+    let (input, (nodes, _)) = many_till(
+        terminated(
+            separated_pair(
+                node,
+                cut(tag(" = ")),
+                cut(delimited(
+                    complete::char('('),
+                    separated_pair(node, tag(", "), node),
+                    complete::char(')'),
+                )),
+            ),
+            cut(alt((line_ending, eof)).context("the end of the node")),
+        ),
+        eof,
+    )(input)?;
+    let map = nodes.into_iter().collect::<Network>();
+    Ok((input, (instructions, map)))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    /// The left/right instructions and the node network.
+    type Parsed<'a> = (Vec<Direction>, Network<'a>);
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let (_, network) =
+            parser(Span::new(input)).map_err(|error| Day08::located_error(input, error))?;
+        Ok(network)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
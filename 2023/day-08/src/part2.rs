@@ -1,59 +1,12 @@
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, alphanumeric1, line_ending, multispace1},
-    combinator::eof,
-    multi::{fold_many1, many1},
-    sequence::{delimited, separated_pair, terminated},
-    IResult, Parser,
-};
+use aoc_common::{Result, Solution};
 use num::integer;
-use std::{collections::BTreeMap, error::Error, fs, time::Instant};
-use tracing::info;
+use tracing::{debug, info};
 
-#[derive(Debug)]
-enum Direction {
-    Left,
-    Right,
-}
-
-#[tracing::instrument(skip(input))]
-fn parser(input: &str) -> IResult<&str, (Vec<Direction>, BTreeMap<&str, (&str, &str)>)> {
-    let (input, instructions) = many1(alt((
-        complete::char('R').map(|_| Direction::Right),
-        complete::char('L').map(|_| Direction::Left),
-    )))(input)?;
-
-    let (input, _) = multispace1(input)?;
-    let (input, map) = fold_many1(
-        terminated(
-            separated_pair(
-                alphanumeric1,
-                tag(" = "),
-                delimited(
-                    complete::char('('),
-                    separated_pair(alphanumeric1, tag(", "), alphanumeric1),
-                    complete::char(')'),
-                ),
-            ),
-            alt((line_ending, eof)),
-        ),
-        BTreeMap::new,
-        |mut acc: BTreeMap<&str, (&str, &str)>, (key, value)| {
-            acc.insert(key, value);
-            acc
-        },
-    )(input)?;
-    Ok((input, (instructions, map)))
-}
+use crate::{Day08, Direction, Network};
 
-#[tracing::instrument(skip(input))]
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let (input, (instructions, map)) = parser(input).expect("should return valid parse");
-    debug_assert_eq!(input, "");
-
-    // info!(?instructions);
-    // info!(?map);
+#[tracing::instrument(skip(instructions, map))]
+pub fn process((instructions, map): &(Vec<Direction>, Network)) -> Result<u64> {
+    debug!(instructions = instructions.len(), nodes = map.len());
 
     let start_nodes = map
         .keys()
@@ -64,58 +17,45 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
 
     let step_count = start_nodes
         .iter()
-        .map(|start| {
+        .map(|start| -> Result<u64> {
             let mut current_node = *start;
-            instructions
-                .iter()
-                .cycle()
-                .enumerate()
-                .find_map(|(index, instruction)| {
-                    let options = map.get(current_node).expect("must always exist");
-                    let next_node = match instruction {
-                        Direction::Left => options.0,
-                        Direction::Right => options.1,
-                    };
-                    if next_node.ends_with("Z") {
-                        Some((index + 1) as u64)
-                    } else {
-                        current_node = next_node;
-                        None
-                    }
-                })
-                .expect("infinite iteratoe must produce output")
+            for (index, instruction) in instructions.iter().cycle().enumerate() {
+                let options = map
+                    .get(current_node)
+                    .ok_or_else(|| Day08::error(format!("there is no node {current_node}")))?;
+                let next_node = match instruction {
+                    Direction::Left => options.0,
+                    Direction::Right => options.1,
+                };
+                if next_node.ends_with("Z") {
+                    return Ok((index + 1) as u64);
+                }
+                current_node = next_node;
+            }
+            Err(Day08::error("there are no instructions").into())
         })
-        .fold(1, |acc: u64, item| integer::lcm(acc, item));
+        .try_fold(1, |acc: u64, item| item.map(|item| integer::lcm(acc, item)))?;
 
     info!(?step_count);
 
     let result = step_count;
-    Ok(result.to_string())
+    Ok(result)
 }
 
 //11A - 2 22A - 3
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day08;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
+    fn test_process() -> Result<()> {
         let _ = tracing_subscriber::fmt::try_init();
         let input_file = "input_test_part2.txt";
-        let input = fs::read_to_string(input_file.to_string()).expect("should be string");
-        // info!(input);
-        assert_eq!("6", process(input.as_str())?);
+        let input = fs::read_to_string(input_file).expect("should be string");
+        assert_eq!(6, process(&Day08::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    // info!(input);
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
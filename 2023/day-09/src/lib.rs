@@ -0,0 +1,43 @@
+use aoc_common::{Result, Solution};
+use nom::{
+    bytes::complete::tag, character::complete, combinator::all_consuming, multi::separated_list1,
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+fn parse_line(input: &str) -> IResult<&str, Vec<i32>> {
+    all_consuming(separated_list1(tag(" "), complete::i32))(input)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    /// The history of readings for each sensor.
+    type Parsed<'a> = Vec<Vec<i32>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        input
+            .lines()
+            .map(|line| {
+                let (_, readings) =
+                    parse_line(line).map_err(|error| Day09::nom_error(input, error))?;
+                Ok(readings)
+            })
+            .collect::<Result<Vec<Vec<i32>>>>()
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
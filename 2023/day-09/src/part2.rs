@@ -1,10 +1,6 @@
-use nom::{bytes::complete::tag, character::complete, multi::separated_list1, IResult};
-use std::{error::Error, fs, time::Instant};
-use tracing::info;
+use aoc_common::{AocError, Result, Solution};
 
-fn parse_line(input: &str) -> IResult<&str, Vec<i32>> {
-    separated_list1(tag(" "), complete::i32)(input)
-}
+use crate::Day09;
 
 #[tracing::instrument(skip(values))]
 fn reduction(values: Vec<i32>) -> Vec<i32> {
@@ -16,12 +12,20 @@ fn reduction(values: Vec<i32>) -> Vec<i32> {
 }
 
 #[tracing::instrument(skip(readings))]
-fn extrapolate(readings: Vec<i32>) -> i32 {
+fn extrapolate(readings: Vec<i32>) -> std::result::Result<i32, AocError> {
     let mut start_numbers: Vec<i32> = vec![];
-    start_numbers.push(readings.first().expect("must exist").clone());
+    start_numbers.push(
+        *readings
+            .first()
+            .ok_or_else(|| Day09::error("the readings never settle to all zeroes"))?,
+    );
     let mut reduced_readings = reduction(readings);
     loop {
-        start_numbers.push(*reduced_readings.first().expect("must exist"));
+        start_numbers.push(
+            *reduced_readings
+                .first()
+                .ok_or_else(|| Day09::error("the readings never settle to all zeroes"))?,
+        );
         reduced_readings = reduction(reduced_readings);
         if reduced_readings.iter().sum::<i32>() == 0
             && reduced_readings.iter().product::<i32>() == 0
@@ -30,46 +34,29 @@ fn extrapolate(readings: Vec<i32>) -> i32 {
         }
     }
 
-    start_numbers.iter().rev().fold(0, |acc, num| num - acc)
+    Ok(start_numbers.iter().rev().fold(0, |acc, num| num - acc))
 }
 
-#[tracing::instrument(skip(input))]
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let all_sensors = input
-        .lines()
-        .map(|line| {
-            let (_, readings) = parse_line(line).expect("should be a vec of numbers");
-            readings
-        })
-        .collect::<Vec<Vec<i32>>>();
-
+#[tracing::instrument(skip(all_sensors))]
+pub fn process(all_sensors: &[Vec<i32>]) -> Result<i32> {
     let result = all_sensors
         .iter()
         .map(|readings| extrapolate(readings.to_vec()))
-        .sum::<i32>();
+        .sum::<std::result::Result<i32, AocError>>()?;
 
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("test_input.txt").expect("should be string");
-        assert_eq!("2", process(input.as_str())?);
+        assert_eq!(2, process(&Day09::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
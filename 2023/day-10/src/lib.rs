@@ -0,0 +1,135 @@
+use aoc_common::{AocError, Dir4, Grid, Result, Solution};
+use glam::IVec2;
+use std::fmt::{self, Display};
+
+pub mod part1;
+pub mod part2;
+
+// | is a vertical pipe connecting north and south.
+// - is a horizontal pipe connecting east and west.
+// L is a 90-degree bend connecting north and east.
+// J is a 90-degree bend connecting north and west.
+// 7 is a 90-degree bend connecting south and west.
+// F is a 90-degree bend connecting south and east.
+// . is ground; there is no pipe in this tile.
+// S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum PipeType {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    StartingPosition,
+    Ground,
+}
+
+/// The pipe as the sketch draws it.
+impl Display for PipeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tile = match self {
+            PipeType::Vertical => '|',
+            PipeType::Horizontal => '-',
+            PipeType::NorthEast => 'L',
+            PipeType::NorthWest => 'J',
+            PipeType::SouthWest => '7',
+            PipeType::SouthEast => 'F',
+            PipeType::StartingPosition => 'S',
+            PipeType::Ground => '.',
+        };
+        write!(f, "{tile}")
+    }
+}
+
+/// Follows the pipe at `position`, entered from the `from` side, to the next
+/// tile and the side that one is entered from.
+fn follow(
+    grid: &Grid<PipeType>,
+    from: Dir4,
+    position: IVec2,
+) -> std::result::Result<(Dir4, IVec2), AocError> {
+    let pipe_type = grid.get(position).ok_or_else(|| {
+        Day10::error(format!(
+            "the loop leads off the sketch at x {}, y {}",
+            position.x, position.y
+        ))
+    })?;
+    let direction_to_go = match (from, pipe_type) {
+        (Dir4::North, PipeType::NorthEast) => Dir4::East,
+        (Dir4::North, PipeType::NorthWest) => Dir4::West,
+        (Dir4::North, PipeType::Vertical) => Dir4::South,
+        (Dir4::South, PipeType::SouthEast) => Dir4::East,
+        (Dir4::South, PipeType::SouthWest) => Dir4::West,
+        (Dir4::South, PipeType::Vertical) => Dir4::North,
+        (Dir4::East, PipeType::NorthEast) => Dir4::North,
+        (Dir4::East, PipeType::SouthEast) => Dir4::South,
+        (Dir4::East, PipeType::Horizontal) => Dir4::West,
+        (Dir4::West, PipeType::NorthWest) => Dir4::North,
+        (Dir4::West, PipeType::SouthWest) => Dir4::South,
+        (Dir4::West, PipeType::Horizontal) => Dir4::East,
+        (from, pipe_type) => {
+            return Err(Day10::error(format!(
+                "the loop runs into {pipe_type:?} from the {from:?} at x {}, y {}",
+                position.x, position.y
+            )))
+        }
+    };
+    Ok((
+        direction_to_go.opposite(),
+        position + direction_to_go.to_ivec2(),
+    ))
+}
+
+/// The tiles of the loop from `position`, entered from the `from` side, each
+/// yielded once [`follow`] has found a pipe there to lead on through. Ends
+/// after the first error, and never otherwise, so stop where the paths meet.
+fn path(
+    grid: &Grid<PipeType>,
+    from: Dir4,
+    position: IVec2,
+) -> impl Iterator<Item = std::result::Result<IVec2, AocError>> + '_ {
+    let mut next = Some((from, position));
+    std::iter::from_fn(move || {
+        let (from, position) = next.take()?;
+        Some(follow(grid, from, position).map(|step| {
+            next = Some(step);
+            position
+        }))
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    /// Every tile of the sketch, including ground.
+    type Parsed<'a> = Grid<PipeType>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(Day10::grid(input, input, |tile| match tile {
+            '|' => Ok(PipeType::Vertical),
+            '-' => Ok(PipeType::Horizontal),
+            'L' => Ok(PipeType::NorthEast),
+            'J' => Ok(PipeType::NorthWest),
+            '7' => Ok(PipeType::SouthWest),
+            'F' => Ok(PipeType::SouthEast),
+            '.' => Ok(PipeType::Ground),
+            'S' => Ok(PipeType::StartingPosition),
+            _ => Err("expected one of |-LJ7F.S"),
+        })?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
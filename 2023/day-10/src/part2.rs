@@ -1,50 +1,11 @@
-use glam::IVec2;
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, multispace0, multispace1},
-    combinator::all_consuming,
-    multi::many1,
-    sequence::terminated,
-    IResult, Parser,
-};
-use nom_locate::LocatedSpan;
-use std::{
-    collections::{BTreeMap, HashMap, HashSet},
-    error::Error,
-    fs,
-    time::Instant,
+use aoc_common::{
+    render::{Colour, Layer, Render},
+    BitGrid, Dir4, Grid, Result, Solution,
 };
-use tracing::info;
+use glam::IVec2;
+use tracing::{debug, info};
 
-// | is a vertical pipe connecting north and south.
-// - is a horizontal pipe connecting east and west.
-// L is a 90-degree bend connecting north and east.
-// J is a 90-degree bend connecting north and west.
-// 7 is a 90-degree bend connecting south and west.
-// F is a 90-degree bend connecting south and east.
-// . is ground; there is no pipe in this tile.
-// S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
-
-#[derive(Debug, Eq, PartialEq)]
-enum PipeType {
-    Vertical,
-    Horizontal,
-    NorthEast,
-    NorthWest,
-    SouthWest,
-    SouthEast,
-    StartingPosition,
-    Ground,
-}
-
-#[derive(Debug, Eq, PartialEq, Clone)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
+use crate::{path, Day10, PipeType};
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 enum Status {
@@ -52,108 +13,50 @@ enum Status {
     Out,
 }
 
-#[derive(Debug)]
-struct PipeInfo<'a> {
-    span: SpanIVec2<'a>,
-    pipe_type: PipeType,
-}
-
-type Span<'a> = LocatedSpan<&'a str>;
-type SpanIVec2<'a> = LocatedSpan<&'a str, IVec2>;
-
-fn with_xy(span: Span) -> SpanIVec2 {
-    let x = span.get_column() as i32 - 1;
-    let y = span.location_line() as i32 - 1;
-    span.map_extra(|_| IVec2::new(x, y))
-}
-
-fn parse_grid(input: Span) -> IResult<Span, HashMap<IVec2, PipeType>> {
-    let (input, output) = all_consuming(many1(terminated(
-        alt((
-            tag("|").map(with_xy).map(|span| PipeInfo {
-                span,
-                pipe_type: PipeType::Vertical,
-            }),
-            tag("-").map(with_xy).map(|span| PipeInfo {
-                span,
-                pipe_type: PipeType::Horizontal,
-            }),
-            tag("L").map(with_xy).map(|span| PipeInfo {
-                span,
-                pipe_type: PipeType::NorthEast,
-            }),
-            tag("J").map(with_xy).map(|span| PipeInfo {
-                span,
-                pipe_type: PipeType::NorthWest,
-            }),
-            tag("7").map(with_xy).map(|span| PipeInfo {
-                span,
-                pipe_type: PipeType::SouthWest,
-            }),
-            tag("F").map(with_xy).map(|span| PipeInfo {
-                span,
-                pipe_type: PipeType::SouthEast,
-            }),
-            tag(".").map(with_xy).map(|span| PipeInfo {
-                span,
-                pipe_type: PipeType::Ground,
-            }),
-            tag("S").map(with_xy).map(|span| PipeInfo {
-                span,
-                pipe_type: PipeType::StartingPosition,
-            }),
-        )),
-        multispace0,
-    )))(input)?;
-
-    // dbg!(output);
-    Ok((
-        input,
-        output
-            .into_iter()
-            .filter_map(|pipe_info| Some((pipe_info.span.extra, pipe_info.pipe_type)))
-            .collect(),
-    ))
-}
-
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let (_input, grid) = parse_grid(Span::new(input)).expect("should be a valid parse");
+pub fn process(grid: &Grid<PipeType>) -> Result<usize> {
     let start_position = grid
-        .iter()
-        .find_map(|(key, value)| (value == &PipeType::StartingPosition).then_some(key))
-        .expect("starting position must exist");
-    let north = *start_position + IVec2::new(0, -1);
+        .position(|pipe_type| pipe_type == &PipeType::StartingPosition)
+        .ok_or_else(|| Day10::error("there is no starting position S"))?;
+    let north = start_position + Dir4::North.to_ivec2();
     let north_positions = grid
-        .get(&north)
-        .is_some_and(|pipe_type| match pipe_type {
-            PipeType::Vertical | PipeType::SouthEast | PipeType::SouthWest => true,
-            _ => false,
+        .get(north)
+        .is_some_and(|pipe_type| {
+            matches!(
+                pipe_type,
+                PipeType::Vertical | PipeType::SouthEast | PipeType::SouthWest
+            )
         })
-        .then_some((Direction::South, north));
-    let south = *start_position + IVec2::new(0, 1);
+        .then_some((Dir4::South, north));
+    let south = start_position + Dir4::South.to_ivec2();
     let south_positions = grid
-        .get(&south)
-        .is_some_and(|pipe_type| match pipe_type {
-            PipeType::Vertical | PipeType::NorthEast | PipeType::NorthWest => true,
-            _ => false,
+        .get(south)
+        .is_some_and(|pipe_type| {
+            matches!(
+                pipe_type,
+                PipeType::Vertical | PipeType::NorthEast | PipeType::NorthWest
+            )
         })
-        .then_some((Direction::North, south));
-    let east = *start_position + IVec2::new(1, 0);
+        .then_some((Dir4::North, south));
+    let east = start_position + Dir4::East.to_ivec2();
     let east_positions = grid
-        .get(&east)
-        .is_some_and(|pipe_type| match pipe_type {
-            PipeType::Horizontal | PipeType::NorthWest | PipeType::SouthWest => true,
-            _ => false,
+        .get(east)
+        .is_some_and(|pipe_type| {
+            matches!(
+                pipe_type,
+                PipeType::Horizontal | PipeType::NorthWest | PipeType::SouthWest
+            )
         })
-        .then_some((Direction::West, east));
-    let west = *start_position + IVec2::new(-1, 0);
+        .then_some((Dir4::West, east));
+    let west = start_position + Dir4::West.to_ivec2();
     let west_positions = grid
-        .get(&west)
-        .is_some_and(|pipe_type| match pipe_type {
-            PipeType::Horizontal | PipeType::SouthEast | PipeType::NorthEast => true,
-            _ => false,
+        .get(west)
+        .is_some_and(|pipe_type| {
+            matches!(
+                pipe_type,
+                PipeType::Horizontal | PipeType::SouthEast | PipeType::NorthEast
+            )
         })
-        .then_some((Direction::East, west));
+        .then_some((Dir4::East, west));
 
     let mut iters = vec![
         north_positions,
@@ -163,118 +66,91 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
     ]
     .into_iter()
     .flatten()
-    .map(|tuple| {
-        std::iter::successors(Some(tuple), |(from_direction, current_position)| {
-            let pipe_type = grid
-                .get(current_position)
-                .expect("should not be asking for a grid position that doesnt exist");
-            // info!(?from_direction, ?current_position, ?pipe_type);
-            let direction_to_go = match (from_direction, pipe_type) {
-                (Direction::North, PipeType::NorthEast) => Direction::East,
-                (Direction::North, PipeType::NorthWest) => Direction::West,
-                (Direction::North, PipeType::Vertical) => Direction::South,
-                (Direction::South, PipeType::SouthEast) => Direction::East,
-                (Direction::South, PipeType::SouthWest) => Direction::West,
-                (Direction::South, PipeType::Vertical) => Direction::North,
-                (Direction::East, PipeType::NorthEast) => Direction::North,
-                (Direction::East, PipeType::SouthEast) => Direction::South,
-                (Direction::East, PipeType::Horizontal) => Direction::West,
-                (Direction::West, PipeType::NorthWest) => Direction::North,
-                (Direction::West, PipeType::SouthWest) => Direction::South,
-                (Direction::West, PipeType::Horizontal) => Direction::East,
-                value => {
-                    unreachable!(
-                        "should not land on ground or loop off into nowhere, {:?}",
-                        value
-                    )
-                }
-            };
-            Some(match direction_to_go {
-                Direction::North => (Direction::South, *current_position + IVec2::new(0, -1)),
-                Direction::South => (Direction::North, *current_position + IVec2::new(0, 1)),
-                Direction::East => (Direction::West, *current_position + IVec2::new(1, 0)),
-                Direction::West => (Direction::East, *current_position + IVec2::new(-1, 0)),
-            })
-        })
-    });
-
-    let path_a = iters.next().expect("path a should exist");
-    let path_b = iters.next().expect("path b should exist");
-    let mut zip_it = path_a.zip(path_b);
-    let mut pipe_locations: HashSet<IVec2> = HashSet::from([*start_position]);
-    while let Some((path_a_node, path_b_node)) = zip_it.next() {
-        pipe_locations.insert(path_a_node.1);
-        pipe_locations.insert(path_b_node.1);
-        if path_a_node.1 == path_b_node.1 {
+    .map(|(from_direction, position)| path(grid, from_direction, position));
+
+    let path_a = iters
+        .next()
+        .ok_or_else(|| Day10::error("no pipe connects to the start"))?;
+    let path_b = iters
+        .next()
+        .ok_or_else(|| Day10::error("only one pipe connects to the start"))?;
+    let zip_it = path_a.zip(path_b);
+    let mut pipe_locations = BitGrid::new(grid.width(), grid.height());
+    pipe_locations.set(start_position);
+    for (path_a_node, path_b_node) in zip_it {
+        let (path_a_node, path_b_node) = (path_a_node?, path_b_node?);
+        pipe_locations.set(path_a_node);
+        pipe_locations.set(path_b_node);
+        if path_a_node == path_b_node {
             break;
         }
     }
 
-    let result: usize = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            let mut status = Status::Out;
-            line.chars()
-                .enumerate()
-                .filter(|(x, _)| {
-                    let position = IVec2::new(*x as i32, y as i32);
-                    let pipe_type = grid.get(&position).expect("should be a valid tile");
-                    if pipe_locations.contains(&position) {
-                        if [
-                            PipeType::StartingPosition,
-                            PipeType::Vertical,
-                            PipeType::SouthEast,
-                            PipeType::SouthWest,
-                        ]
-                        .contains(pipe_type)
-                        {
-                            status = match status {
-                                Status::In => Status::Out,
-                                Status::Out => Status::In,
-                            };
-                        }
-                        false
-                    } else {
-                        match status {
-                            Status::In => true,
-                            Status::Out => false,
-                        }
-                    }
-                })
-                .count()
-        })
-        .sum::<usize>();
+    let mut inside = BitGrid::new(grid.width(), grid.height());
+    for y in 0..grid.size().y {
+        let mut status = Status::Out;
+        for x in 0..grid.size().x {
+            let position = IVec2::new(x, y);
+            if pipe_locations.test(position) {
+                if [
+                    PipeType::StartingPosition,
+                    PipeType::Vertical,
+                    PipeType::SouthEast,
+                    PipeType::SouthWest,
+                ]
+                .contains(&grid[position])
+                {
+                    status = match status {
+                        Status::In => Status::Out,
+                        Status::Out => Status::In,
+                    };
+                }
+            } else if status == Status::In {
+                inside.set(position);
+            }
+        }
+    }
+    debug!(
+        "the loop and the tiles inside it\n{}",
+        Render::new(grid)
+            .box_drawing()
+            .layer(Layer::new(&pipe_locations).colour(Colour::Blue))
+            .layer(Layer::new(&inside).colour(Colour::Green).glyph('I'))
+    );
+    let result = inside.count();
 
     info!(?result);
 
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day10;
+    use aoc_common::Solution;
+    use std::fs;
 
     use rstest::rstest;
 
     #[rstest]
-    #[case("test_input21.txt", "4")]
-    #[case("test_input22.txt", "8")]
-    #[case("test_input23.txt", "10")]
-    fn test_process(#[case] file: &str, #[case] output: &str) -> Result<(), Box<dyn Error>> {
+    #[case("test_input21.txt", 4)]
+    #[case("test_input22.txt", 8)]
+    #[case("test_input23.txt", 10)]
+    fn test_process(#[case] file: &str, #[case] output: usize) -> Result<()> {
         let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string(file).expect("should be string");
-        assert_eq!(output, process(input.as_str())?);
+        assert_eq!(output, process(&Day10::parse(input.as_str())?)?);
         Ok(())
     }
-}
 
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
+    #[test]
+    fn test_off_the_sketch() -> Result<()> {
+        let error = process(&Day10::parse("S-\n|.\n")?).expect_err("should not solve");
+        assert!(
+            error.to_string().contains("the loop leads off the sketch"),
+            "{error}"
+        );
+        Ok(())
+    }
 }
@@ -1,7 +1,11 @@
+use aoc_common::{
+    alloc::{self, Allocations},
+    input, trace,
+};
 use glam::I64Vec2;
 use itertools::Itertools;
-use std::{error::Error, fs, time::Instant};
-use tracing::{info, span, Level};
+use std::{env, error::Error, path::PathBuf, time::Instant};
+use tracing::{span, Level};
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str, expansion_size: i64) -> Result<String, Box<dyn Error>> {
@@ -98,8 +102,8 @@ pub fn process(input: &str, expansion_size: i64) -> Result<String, Box<dyn Error
                 };
 
                 let v = (galaxy_a_expanded - galaxy_b_expanded).abs();
-                let distance = (v.x + v.y).abs();
-                distance
+
+                (v.x + v.y).abs()
             })
         })
         .sum::<i64>();
@@ -108,13 +112,16 @@ pub fn process(input: &str, expansion_size: i64) -> Result<String, Box<dyn Error
 
 #[tracing::instrument]
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _trace = trace::init_from_env().expect("should set up tracing");
     let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
+    let path = env::args_os().nth(1).map(PathBuf::from);
+    let input = input::read(2023, 11, path).expect("should find input");
+    let allocations = Allocations::now();
     println!(
         "{:?}",
         process(input.as_str(), 1000000).expect("should be a string")
     );
     let elapsed = now.elapsed();
     println!("Elapsed : {:.2?}", elapsed);
+    alloc::report(Allocations::since(allocations));
 }
@@ -0,0 +1,28 @@
+use aoc_common::{Grid, Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    /// The unexpanded image.
+    type Parsed<'a> = Grid<char>;
+    type Part1 = i32;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(Day11::char_grid(input, input, ".#")?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed, 1000000)
+    }
+}
@@ -1,58 +1,34 @@
+use aoc_common::{trace::Steps, Grid, Result};
 use glam::IVec2;
-use std::{error::Error, fs, time::Instant};
-use tracing::info;
+use std::iter;
+use tracing::debug;
 
-fn transpose(input: Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let rows = input.len();
-    let cols = input[0].len();
-
-    let transposed: Vec<Vec<_>> = (0..cols)
-        .map(|col| (0..rows).map(|row| input[row][col]).collect())
+/// Doubles every row without a galaxy.
+fn xpand(grid: &Grid<char>) -> Grid<char> {
+    let cells = grid
+        .rows()
+        .flat_map(|row| {
+            let copies = if row.contains(&'#') { 1 } else { 2 };
+            iter::repeat_n(row, copies).flatten().copied()
+        })
         .collect();
-    transposed
+    Grid::new(grid.width(), cells)
 }
 
-fn xpand(input: Vec<Vec<char>>) -> Vec<Vec<char>> {
-    // expands in the x direction
-    let mut x_expanded: Vec<Vec<char>> = Vec::new();
-    for line in input.iter() {
-        x_expanded.push(line.to_vec());
-        if !line.contains(&'#') {
-            x_expanded.push(line.to_vec());
-        }
-    }
-    x_expanded
+fn expand(grid: &Grid<char>) -> Grid<char> {
+    xpand(&xpand(grid).transpose()).transpose()
 }
 
-fn parse_data_expand(input: &str) -> Vec<Vec<char>> {
-    let in_grid = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-
-    transpose(xpand(transpose(xpand(in_grid))))
-}
-
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let grid = parse_data_expand(input);
+pub fn process(grid: &Grid<char>) -> Result<i32> {
+    let grid = expand(grid);
 
     let galaxies = grid
         .iter()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.iter()
-                .enumerate()
-                .filter_map(|(x, ch)| {
-                    if ch == &'#' {
-                        Some(IVec2::new(x as i32, y as i32))
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<IVec2>>()
-        })
+        .filter_map(|(position, ch)| (ch == &'#').then_some(position))
         .collect::<Vec<IVec2>>();
+    debug!(galaxies = galaxies.len());
 
+    let mut steps = Steps::new();
     let result = galaxies
         .iter()
         .enumerate()
@@ -61,42 +37,30 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
                 .iter()
                 .skip(i + 1)
                 .map(|other| {
-                    // info!(?val);
-                    // info!(?other);
                     let cal = (other.x - val.x).abs() + (other.y - val.y).abs();
-                    // info!(cal);
+                    if steps.step() {
+                        debug!(%val, %other, cal, "pair");
+                    }
                     cal
                 })
                 .sum::<i32>()
         })
         .sum::<i32>();
-    // info!(?galaxies);
-
-    // for line in grid.iter() {
-    //     info!(?line);
-    // }
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day11;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("test_input.txt").expect("should be string");
-        assert_eq!("374", process(input.as_str())?);
+        assert_eq!(374, process(&Day11::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
@@ -1,77 +1,32 @@
+use aoc_common::{trace::Steps, Grid, Result};
 use core::cmp::{max, min};
 use glam::IVec2;
-use std::{error::Error, fs, time::Instant};
-use tracing::info;
+use tracing::debug;
 
-fn transpose(input: Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let rows = input.len();
-    let cols = input[0].len();
-
-    let transposed: Vec<Vec<_>> = (0..cols)
-        .map(|col| (0..rows).map(|row| input[row][col]).collect())
-        .collect();
-    transposed
-}
-
-fn find_expanded_rows_cols(input: &str) -> (Vec<i64>, Vec<i64>) {
-    // expands in the x direction>
-    let row_expanded = parse_data(input)
-        .iter()
+fn find_expanded_rows_cols(grid: &Grid<char>) -> (Vec<i64>, Vec<i64>) {
+    let row_expanded = grid
+        .rows()
         .enumerate()
-        .filter_map(|(i, line)| {
-            // info!(?line);
-            if !line.contains(&'#') {
-                Some(i as i64)
-            } else {
-                None
-            }
-        })
+        .filter_map(|(i, line)| (!line.contains(&'#')).then_some(i as i64))
         .collect::<Vec<i64>>();
-    let col_expanded = transpose(parse_data(input))
-        .iter()
+    let col_expanded = grid
+        .columns()
         .enumerate()
-        .filter_map(|(i, line)| {
-            // info!(?line);
-            if !line.contains(&'#') {
-                Some(i as i64)
-            } else {
-                None
-            }
-        })
+        .filter_map(|(i, mut line)| (!line.any(|ch| ch == &'#')).then_some(i as i64))
         .collect::<Vec<i64>>();
     (row_expanded, col_expanded)
 }
 
-fn parse_data(input: &str) -> Vec<Vec<char>> {
-    input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>()
-}
-
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let grid = parse_data(input);
-    let (expanded_rows, expanded_cols) = find_expanded_rows_cols(input);
+pub fn process(grid: &Grid<char>, expand_multiplier: i64) -> Result<i64> {
+    let (expanded_rows, expanded_cols) = find_expanded_rows_cols(grid);
 
     let galaxies = grid
         .iter()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.iter()
-                .enumerate()
-                .filter_map(|(x, ch)| {
-                    if ch == &'#' {
-                        Some(IVec2::new(x as i32, y as i32))
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<IVec2>>()
-        })
+        .filter_map(|(position, ch)| (ch == &'#').then_some(position))
         .collect::<Vec<IVec2>>();
+    debug!(galaxies = galaxies.len(), ?expanded_rows, ?expanded_cols);
 
-    let expand_multiplier = 1000000;
-
+    let mut steps = Steps::new();
     let result = galaxies
         .iter()
         .enumerate()
@@ -104,46 +59,31 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
                             }
                         })
                         .sum::<i64>();
-                    // info!(?val);
-                    // info!(?other);
-                    // info!(row_expander);
-                    // info!(col_expander);
                     let cal = (other.x - val.x).abs() + (other.y - val.y).abs();
-                    // info!(cal);
                     let res = cal as i64 + (col_expander + row_expander) * (expand_multiplier - 1);
-                    // info!(res);
+                    if steps.step() {
+                        debug!(%val, %other, row_expander, col_expander, cal, res, "pair");
+                    }
                     res
                 })
                 .sum::<i64>()
         })
         .sum::<i64>();
-    // info!(?galaxies);
-
-    // for line in grid.iter() {
-    //     info!(?line);
-    // }
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day11;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("test_input.txt").expect("should be string");
-        assert_eq!("8410", process(input.as_str())?);
+        assert_eq!(8410, process(&Day11::parse(input.as_str())?, 100)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
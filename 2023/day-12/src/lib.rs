@@ -0,0 +1,146 @@
+use aoc_common::{Result, Solution};
+use std::collections::HashMap;
+
+pub mod part1;
+pub mod part2;
+
+const OPERATIONAL: u8 = b'.';
+const DAMAGED: u8 = b'#';
+const UNKNOWN: u8 = b'?';
+
+fn arrangements(data: &[u8], groups: &[u32]) -> u64 {
+    let mut cache = HashMap::default();
+    dfs(&mut cache, data, groups, 0, 0, 0)
+}
+
+#[tracing::instrument(level = "trace", skip(cache, data, groups))]
+fn dfs(
+    cache: &mut HashMap<(usize, usize, u32), u64>,
+    data: &[u8],
+    groups: &[u32],
+    from: usize,
+    group: usize,
+    size: u32,
+) -> u64 {
+    if from >= data.len() {
+        // exhausted all groups
+        if group >= groups.len() {
+            return 1;
+        }
+
+        // the line ends with a "damaged" symbol and we've matched that last group
+        if group == groups.len() - 1 && groups[group] == size {
+            return 1;
+        }
+
+        return 0;
+    }
+
+    match data[from] {
+        DAMAGED => {
+            // we do not expect more damaged spots, thus failed to match
+            if group >= groups.len() || size + 1 > groups[group] {
+                return 0;
+            }
+
+            dfs(cache, data, groups, from + 1, group, size + 1)
+        }
+
+        UNKNOWN => {
+            if let Some(answer) = cache.get(&(from, group, size)).copied() {
+                return answer;
+            }
+
+            let mut ways = 0;
+
+            // if we did not encounter any damaged cells,
+            // we can treat this one as undamaged
+            if size == 0 {
+                ways += dfs(cache, data, groups, from + 1, group, size);
+            }
+
+            // if we need more damaged cells to complete our match,
+            // we can treat the current cell as damaged
+            if group < groups.len() && size < groups[group] {
+                ways += dfs(cache, data, groups, from + 1, group, size + 1);
+            }
+
+            // we have the correct number of damaged cells, so we can just
+            // treat this one as undamaged in order to complete the match
+            if group < groups.len() && size == groups[group] {
+                ways += dfs(cache, data, groups, from + 1, group + 1, 0);
+            }
+
+            cache.insert((from, group, size), ways);
+            ways
+        }
+
+        // OPERATIONAL, the only other spring `parse` lets through
+        _ => {
+            // skip sequence of operational spots
+            if size == 0 {
+                return dfs(cache, data, groups, from + 1, group, size);
+            }
+
+            // the current combination failed to match a proper sequence from the input
+            if group >= groups.len() || size != groups[group] {
+                return 0;
+            }
+
+            // we have a match: process the next group
+            dfs(cache, data, groups, from + 1, group + 1, 0)
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    /// Each row's condition record and its list of damaged group sizes.
+    type Parsed<'a> = Vec<(&'a str, Vec<u32>)>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        input
+            .lines()
+            .map(|line| {
+                let (l, r) = line.rsplit_once(' ').ok_or_else(|| {
+                    Day12::parse_error(input, line, "expected a record and its groups")
+                })?;
+                let l = l.trim();
+                if let Some(position) = l
+                    .bytes()
+                    .position(|c| ![OPERATIONAL, DAMAGED, UNKNOWN].contains(&c))
+                {
+                    return Err(Day12::parse_error(
+                        input,
+                        &l[position..],
+                        "expected a spring, one of .#?",
+                    ));
+                }
+                let groups = r
+                    .trim()
+                    .split(',')
+                    .map(|x| {
+                        x.parse()
+                            .map_err(|error| Day12::parse_error(input, x, error))
+                    })
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok((l, groups))
+            })
+            .collect::<std::result::Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::{Grid, Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    /// Each pattern of ash and rocks.
+    type Parsed<'a> = Vec<Grid<char>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        input
+            .split("\n\n")
+            .map(|pattern| Ok(Day13::char_grid(input, pattern, ".#")?))
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -1,26 +1,14 @@
+use aoc_common::{Grid, Result};
 use core::cmp::min;
-use std::{error::Error, fs, time::Instant};
-use tracing::info;
+use tracing::{debug, info};
 
-fn transpose_grid(input: Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let rows = input.len();
-    let cols = input[0].len();
-
-    let transposed: Vec<Vec<_>> = (0..cols)
-        .map(|col| (0..rows).map(|row| input[row][col]).collect())
-        .collect();
-    transposed
-}
-
-fn find_horizontal_reflections(input: &str, transpose: bool) -> u32 {
-    let mut pattern = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-
-    if transpose {
-        pattern = transpose_grid(pattern);
-    }
+fn find_horizontal_reflections(pattern: &Grid<char>, transpose: bool) -> u32 {
+    let pattern = if transpose {
+        pattern.transpose()
+    } else {
+        pattern.clone()
+    };
+    let pattern = pattern.rows().collect::<Vec<_>>();
 
     for r in 1..pattern.len() {
         let mut above = Vec::new();
@@ -30,11 +18,11 @@ fn find_horizontal_reflections(input: &str, transpose: bool) -> u32 {
         let range = min(below.len(), above.len());
 
         let match_criteria = above[..range]
-            .into_iter()
-            .zip(below[..range].into_iter())
+            .iter()
+            .zip(below[..range].iter())
             .map(|(x, y)| {
-                x.into_iter()
-                    .zip(y.into_iter())
+                x.iter()
+                    .zip(y.iter())
                     .map(|(a, b)| if a == b { 0 } else { 1 })
                     .sum::<u32>()
             })
@@ -45,13 +33,13 @@ fn find_horizontal_reflections(input: &str, transpose: bool) -> u32 {
         }
     }
 
-    return 0;
+    0
 }
 
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let patterns = input.split("\n\n").collect::<Vec<&str>>();
-
-    // info!("{}", patterns[0]);
+pub fn process(patterns: &[Grid<char>]) -> Result<u32> {
+    if let Some(pattern) = patterns.first() {
+        debug!("the first pattern\n{pattern}");
+    }
 
     let horizontal_results = patterns
         .iter()
@@ -67,28 +55,21 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
 
     info!(result);
 
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day13;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("test_input.txt").expect("should be string");
-        assert_eq!("400", process(input.as_str())?);
+        assert_eq!(400, process(&Day13::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
@@ -0,0 +1,28 @@
+use aoc_common::{Grid, Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    /// The platform before any tilting.
+    type Parsed<'a> = Grid<char>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(Day14::char_grid(input, input, ".#O")?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
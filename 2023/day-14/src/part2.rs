@@ -1,103 +1,80 @@
-use std::{error::Error, fs, str::from_utf8, time::Instant};
-use tracing::info;
-
-fn transpose_grid(input: Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let rows = input.len();
-    let cols = input[0].len();
-
-    let transposed: Vec<Vec<_>> = (0..cols)
-        .map(|col| (0..rows).map(|row| input[row][col]).collect())
-        .collect();
-    transposed
-}
-
-fn tilt(grid: Vec<Vec<char>>, transpose: bool, reverse: bool) -> Vec<Vec<char>> {
-    let mut tilt_grid = grid.clone();
-    if transpose {
-        tilt_grid = transpose_grid(grid.clone());
-    }
-    tilt_grid = tilt_grid
-        .iter()
-        .map(|row| {
+use aoc_common::{trace::Steps, Grid, Result};
+use tracing::{debug, info};
+
+/// Rolls the round rocks of every row to its end, or to its start when
+/// `reverse`, until they hit a cube rock.
+fn roll_rows(grid: &Grid<char>, reverse: bool) -> Grid<char> {
+    let cells = grid
+        .rows()
+        .flat_map(|row| {
             let joined: String = row.iter().collect();
             let substrings = joined.split("#").collect::<Vec<_>>();
             let sorted_substrings = substrings
                 .iter()
                 .map(|substring| {
-                    let mut sort_substring = substring.to_string().into_bytes().clone();
+                    let mut sort_substring = substring.to_string().into_bytes();
                     sort_substring.sort();
                     if reverse {
                         sort_substring.reverse();
                     }
-                    String::from_utf8(sort_substring).expect("should be valid")
+                    String::from_utf8(sort_substring).expect("parse only lets ASCII through")
                 })
                 .collect::<Vec<String>>();
             sorted_substrings.join("#").chars().collect::<Vec<char>>()
         })
-        .collect::<Vec<Vec<char>>>();
+        .collect();
+    Grid::new(grid.width(), cells)
+}
 
+fn tilt(grid: &Grid<char>, transpose: bool, reverse: bool) -> Grid<char> {
     if transpose {
-        tilt_grid = transpose_grid(tilt_grid);
+        roll_rows(&grid.transpose(), reverse).transpose()
+    } else {
+        roll_rows(grid, reverse)
     }
-    tilt_grid
 }
 
-fn spin_cycle(grid: Vec<Vec<char>>) -> Vec<Vec<char>> {
+fn spin_cycle(grid: &Grid<char>) -> Grid<char> {
     let north = tilt(grid, true, true);
-    let west = tilt(north, false, true);
-    let south = tilt(west, true, false);
-    let east = tilt(south, false, false);
-    east
-}
+    let west = tilt(&north, false, true);
+    let south = tilt(&west, true, false);
 
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let grid = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
+    tilt(&south, false, false)
+}
 
-    // for i in grid.iter() {
-    //     info!(?i);
-    // }
-    //
-    // info!("****************************************************************************");
+pub fn process(grid: &Grid<char>) -> Result<u32> {
+    debug!("before spinning\n{grid}");
 
-    let mut spins: Vec<Vec<Vec<char>>> = Vec::new();
+    let mut spins: Vec<Grid<char>> = Vec::new();
     let mut count = 0;
-    let mut location = 0;
+    let mut steps = Steps::new();
 
     spins.push(grid.clone());
 
-    loop {
-        let start = spins.last().clone().expect("should exist");
-        let spin = spin_cycle(start.to_vec());
+    let location = loop {
+        let start = spins.last().expect("should exist");
+        let spin = spin_cycle(start);
 
         count += 1;
-        if spins.contains(&spin) {
-            for i in 0..spins.len() {
-                if spins[i] == spin {
-                    location = i;
-                    break;
-                }
-            }
-            break;
+        if steps.step() {
+            debug!("after {count} spins\n{spin}");
         }
-        spins.push(spin.clone());
-    }
+        if let Some(location) = spins.iter().position(|s| s == &spin) {
+            break location;
+        }
+        spins.push(spin);
+    };
 
-    info!(count);
-    info!(location);
+    info!(count, location, "the spins repeat");
 
-    let position = (1000000000 - location) % (count - location) + location as usize;
+    let position = (1000000000 - location) % (count - location) + location;
 
     let final_grid = &spins[position];
 
-    // for i in final_grid.iter() {
-    //     info!(?i);
-    // }
+    debug!("after 1000000000 spins\n{final_grid}");
 
     let result = final_grid
-        .iter()
+        .rows()
         .rev()
         .enumerate()
         .map(|(i, row)| {
@@ -109,45 +86,32 @@ fn process(input: &str) -> Result<String, Box<dyn Error>> {
         })
         .sum::<u32>();
 
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day14;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_spin() -> Result<(), Box<dyn Error>> {
+    fn test_spin() -> Result<()> {
         let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("test_input.txt").expect("should be string");
         let output = fs::read_to_string("2cycle_output.txt").expect("should be string");
-        let output_grid = output
-            .lines()
-            .map(|line| line.chars().collect::<Vec<char>>())
-            .collect::<Vec<Vec<char>>>();
-        let grid = input
-            .lines()
-            .map(|line| line.chars().collect::<Vec<char>>())
-            .collect::<Vec<Vec<char>>>();
-        assert_eq!(output_grid, spin_cycle(spin_cycle(grid)));
+        let output_grid = Day14::parse(output.as_str())?;
+        let grid = Day14::parse(input.as_str())?;
+        assert_eq!(output_grid, spin_cycle(&spin_cycle(&grid)));
         Ok(())
     }
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
+    fn test_process() -> Result<()> {
         let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("test_input.txt").expect("should be string");
-        assert_eq!("64", process(input.as_str())?);
+        assert_eq!(64, process(&Day14::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
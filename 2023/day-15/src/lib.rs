@@ -0,0 +1,71 @@
+use aoc_common::{Result, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1},
+    combinator::{all_consuming, map, value},
+    sequence::{pair, preceded},
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// What a step does to the box its label hashes to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// `-`, taking the lens with the label out.
+    Remove,
+    /// `=` and a focal length, putting in a lens or swapping the one there.
+    Insert(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step<'a> {
+    /// The whole step, which part 1 hashes.
+    pub text: &'a str,
+    pub label: &'a str,
+    pub op: Op,
+}
+
+fn step(input: &str) -> IResult<&str, (&str, Op)> {
+    all_consuming(pair(
+        alpha1,
+        alt((
+            value(Op::Remove, tag("-")),
+            map(preceded(tag("="), complete::u32), Op::Insert),
+        )),
+    ))(input)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    /// The comma separated steps of the initialization sequence.
+    type Parsed<'a> = Vec<Step<'a>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input
+            .split(",")
+            .map(|text| {
+                let text = text.trim();
+                let (_, (label, op)) =
+                    step(text).map_err(|error| Day15::nom_error(input, error))?;
+                Ok(Step { text, label, op })
+            })
+            .collect::<std::result::Result<_, aoc_common::AocError>>()?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
@@ -1,138 +1,80 @@
-use std::{
-    cmp::max,
-    collections::{HashSet, VecDeque},
-    error::Error,
-    fs,
-    time::Instant,
-};
-use tracing::info;
+use aoc_common::{trace::Steps, Dir4, DirBitGrid, Grid, Result};
+use glam::IVec2;
+use std::{cmp::max, collections::VecDeque};
+use tracing::debug;
 
-fn find_energized_quant(input: &str, start: (i32, i32, i32, i32)) -> u32 {
-    let grid = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-    let mut cache: HashSet<(i32, i32, i32, i32)> = HashSet::new();
-    let mut energized: HashSet<(i32, i32)> = HashSet::new();
-    let mut light_startpoints: VecDeque<(i32, i32, i32, i32)> = VecDeque::from([start]);
+fn find_energized_quant(grid: &Grid<char>, start: (IVec2, Dir4), steps: &mut Steps) -> u32 {
+    let mut cache = DirBitGrid::new(grid.width(), grid.height());
+    let mut light_startpoints: VecDeque<(IVec2, Dir4)> = VecDeque::from([start]);
 
-    while !light_startpoints.is_empty() {
-        let (r, c, dr, dc) = light_startpoints.pop_back().unwrap();
-        // info!("{},{}", r, c);
-        // info!("q len - {}", light_startpoints.len());
-        let r = r + dr;
-        let c = c + dc;
-        // info!("{},{}", c, r);
-        if r < 0 || r as usize >= grid.len() || c < 0 || c as usize >= grid[0].len() {
+    while let Some((position, direction)) = light_startpoints.pop_back() {
+        let position = position + direction.to_ivec2();
+        let Some(&ch) = grid.get(position) else {
             continue;
+        };
+        if steps.step() {
+            debug!(%position, ?direction, %ch, queued = light_startpoints.len(), "beam");
         }
-        let ch = grid[r as usize][c as usize];
-        // info!("The character is {}", ch);
 
-        // info!("starting check");
-
-        if ch == '.' || (ch == '-' && dc != 0) || (ch == '|' && dr != 0) {
-            // info!("scenario 1");
-            if !cache.contains(&(r, c, dr, dc)) {
-                // info!("{},{},{},{}", r, c, dr, dc);
-                cache.insert((r, c, dr, dc));
-                light_startpoints.push_front((r, c, dr, dc));
-                if !energized.contains(&(r, c)) {
-                    energized.insert((r, c));
-                }
-            }
-        } else if ch == '/' {
-            // info!("scenario 2");
-            let (dr, dc) = (-dc, -dr);
-            if !cache.contains(&(r, c, dr, dc)) {
-                // info!("{},{},{},{}", r, c, dr, dc);
-                cache.insert((r, c, dr, dc));
-                light_startpoints.push_front((r, c, dr, dc));
-                if !energized.contains(&(r, c)) {
-                    energized.insert((r, c));
-                }
-            }
-        } else if ch == '\\' {
-            // info!("scenario 3");
-            let (dr, dc) = (dc, dr);
-            if !cache.contains(&(r, c, dr, dc)) {
-                // info!("{},{},{},{}", r, c, dr, dc);
-                cache.insert((r, c, dr, dc));
-                light_startpoints.push_front((r, c, dr, dc));
-                if !energized.contains(&(r, c)) {
-                    energized.insert((r, c));
-                }
-            }
-        } else {
-            if ch == '|' && dc != 0 {
-                // info!("scenario 4");
-                for (dr, dc) in [(1, 0), (-1, 0)] {
-                    // info!("dr, dc = {},{}", dr, dc);
-                    if !cache.contains(&(r, c, dr, dc)) {
-                        // info!("{},{},{},{}", r, c, dr, dc);
-                        cache.insert((r, c, dr, dc));
-                        light_startpoints.push_front((r, c, dr, dc));
-                        if !energized.contains(&(r, c)) {
-                            energized.insert((r, c));
-                        }
-                    }
-                }
-            }
-            if ch == '-' && dr != 0 {
-                // info!("scenario 5");
-                for (dr, dc) in [(0, 1), (0, -1)] {
-                    // info!("dr, dc = {},{}", dr, dc);
-                    if !cache.contains(&(r, c, dr, dc)) {
-                        // info!("{},{},{},{}", r, c, dr, dc);
-                        cache.insert((r, c, dr, dc));
-                        light_startpoints.push_front((r, c, dr, dc));
-                        if !energized.contains(&(r, c)) {
-                            energized.insert((r, c));
-                        }
-                    }
-                }
+        // the same direction twice when the beam doesn't split
+        let directions = match (ch, direction) {
+            ('|', Dir4::East | Dir4::West) => [Dir4::South, Dir4::North],
+            ('-', Dir4::North | Dir4::South) => [Dir4::East, Dir4::West],
+            ('/' | '\\', _) => [direction.reflect(ch); 2],
+            _ => [direction; 2],
+        };
+        for direction in directions {
+            if cache.set(position, direction) {
+                light_startpoints.push_front((position, direction));
             }
         }
-        // info!("end of iteration")
     }
-    energized.len() as u32
+    let energized = cache.cells().count();
+    debug!(?start, energized);
+    energized as u32
 }
 
-fn process(input: &str) -> Result<String, Box<dyn Error>> {
-    let tot_rows = input.lines().count() as i32;
-    let tot_cols = input.lines().next().unwrap().trim().len() as i32;
+pub fn process(grid: &Grid<char>) -> Result<u32> {
+    let tot_rows = grid.height() as i32;
+    let tot_cols = grid.width() as i32;
     let mut result = 0;
+    let mut steps = Steps::new();
     for r in 0..tot_rows {
-        result = max(find_energized_quant(input, (r, -1, 0, 1)), result);
-        result = max(find_energized_quant(input, (r, tot_cols, 0, -1)), result);
+        result = max(
+            find_energized_quant(grid, (IVec2::new(-1, r), Dir4::East), &mut steps),
+            result,
+        );
+        result = max(
+            find_energized_quant(grid, (IVec2::new(tot_cols, r), Dir4::West), &mut steps),
+            result,
+        );
     }
-    for c in 0..tot_cols as i32 {
-        result = max(find_energized_quant(input, (-1, c, 1, 0)), result);
-        result = max(find_energized_quant(input, (-1, tot_rows, -1, 0)), result);
+    for c in 0..tot_cols {
+        result = max(
+            find_energized_quant(grid, (IVec2::new(c, -1), Dir4::South), &mut steps),
+            result,
+        );
+        result = max(
+            find_energized_quant(grid, (IVec2::new(c, tot_rows), Dir4::North), &mut steps),
+            result,
+        );
     }
 
-    Ok(result.to_string())
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day16;
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
         let input = fs::read_to_string("test_input.txt").expect("should be string");
-        assert_eq!("51", process(input.as_str())?);
+        assert_eq!(51, process(&Day16::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
@@ -0,0 +1,28 @@
+use aoc_common::{Result, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day{{dd}};
+
+impl Solution for Day{{dd}} {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+
+    /// The lines of the puzzle input.
+    type Parsed<'a> = Vec<&'a str>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+        part1::process(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+        part2::process(parsed)
+    }
+}
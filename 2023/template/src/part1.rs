@@ -1,30 +1,24 @@
-use std::{error::Error, fs, time::Instant};
-use tracing::info;
+use aoc_common::Result;
 
-fn process(_input: &str) -> Result<String, Box<dyn Error>> {
-    let result = "";
-    Ok(result.to_string())
+#[tracing::instrument(skip(_lines))]
+pub fn process(_lines: &[&str]) -> Result<u32> {
+    let result = 0;
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day{{dd}};
+    use aoc_common::Solution;
+    use std::fs;
 
     #[test]
-    fn test_process() -> Result<(), Box<dyn Error>> {
-        tracing_subscriber::fmt::init();
-        let input = fs::read_to_string("input_test.txt").expect("should be string");
-        assert_eq!("13", process(input.as_str())?);
+    #[ignore = "example answer not filled in yet"]
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let input = fs::read_to_string("test_input.txt").expect("should be string");
+        assert_eq!(0, process(&Day{{dd}}::parse(input.as_str())?)?);
         Ok(())
     }
 }
-
-#[tracing::instrument]
-fn main() {
-    tracing_subscriber::fmt::init();
-    let now = Instant::now();
-    let input = fs::read_to_string("input1.txt").expect("should be string");
-    println!("{:?}", process(input.as_str()).expect("should be a string"));
-    let elapsed = now.elapsed();
-    println!("Elapsed : {:.2?}", elapsed);
-}
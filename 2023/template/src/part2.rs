@@ -0,0 +1,24 @@
+use aoc_common::Result;
+
+#[tracing::instrument(skip(_lines))]
+pub fn process(_lines: &[&str]) -> Result<u32> {
+    let result = 0;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day{{dd}};
+    use aoc_common::Solution;
+    use std::fs;
+
+    #[test]
+    #[ignore = "example answer not filled in yet"]
+    fn test_process() -> Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let input = fs::read_to_string("test_input.txt").expect("should be string");
+        assert_eq!(0, process(&Day{{dd}}::parse(input.as_str())?)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use aoc_common::{input, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Which way a rejected answer was off, when the site said so.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// An answer the site rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rejected {
+    pub part: u8,
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// The accepted answers of a single day, and every answer rejected on the way.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Day {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected: Vec<Rejected>,
+}
+
+impl Day {
+    fn part(&mut self, part: u8) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+/// How a freshly computed answer compares to the accepted one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Unchanged,
+    Changed { expected: String },
+    New,
+}
+
+/// Every accepted answer of this user, keyed by year and then by the
+/// zero-padded day, e.g. `[2023.07]`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Day>>);
+
+impl Answers {
+    /// The answers file, next to the inputs they were computed from.
+    pub fn path() -> PathBuf {
+        input::cache_dir().join("answers.toml")
+    }
+
+    /// Loads the answers at `path`, or none at all when it doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Answers> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let answers = fs::read_to_string(path)?;
+        toml::from_str(&answers).map_err(|error| format!("{}: {error}", path.display()).into())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    fn day(&self, year: u16, day: u8) -> Option<&Day> {
+        self.0.get(&year.to_string())?.get(&format!("{day:02}"))
+    }
+
+    fn day_mut(&mut self, year: u16, day: u8) -> &mut Day {
+        self.0
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("{day:02}"))
+            .or_default()
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        let day = self.day(year, day)?;
+        match part {
+            1 => day.part1.as_deref(),
+            _ => day.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, part: u8, answer: String) {
+        *self.day_mut(year, day).part(part) = Some(answer);
+    }
+
+    pub fn reject(&mut self, year: u16, day: u8, part: u8, answer: String, hint: Option<Hint>) {
+        self.day_mut(year, day)
+            .rejected
+            .push(Rejected { part, answer, hint });
+    }
+
+    /// Explains why submitting `answer` would be pointless: the part is
+    /// already solved, the answer was already rejected, or it lies outside
+    /// the bounds set by earlier too-high and too-low answers.
+    pub fn doomed(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<String> {
+        if let Some(accepted) = self.get(year, day, part) {
+            return Some(format!("part {part} was already solved with {accepted}"));
+        }
+        let rejected = self.day(year, day).map_or(&[][..], |day| &day.rejected[..]);
+        let rejected = rejected.iter().filter(|rejected| rejected.part == part);
+        let value = answer.parse::<i128>().ok();
+        for guess in rejected {
+            if guess.answer == answer {
+                return Some(format!("{answer} was already rejected"));
+            }
+            let (Some(value), Ok(bound)) = (value, guess.answer.parse::<i128>()) else {
+                continue;
+            };
+            match guess.hint {
+                Some(Hint::TooHigh) if value >= bound => {
+                    return Some(format!("{answer} is not below {bound}, which was too high"))
+                }
+                Some(Hint::TooLow) if value <= bound => {
+                    return Some(format!("{answer} is not above {bound}, which was too low"))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Check {
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer => Check::Unchanged,
+            Some(expected) => Check::Changed {
+                expected: expected.to_string(),
+            },
+            None => Check::New,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let mut answers = Answers::default();
+        answers.set(2023, 7, 1, "252052080".to_string());
+        answers.set(2023, 7, 2, "252898370".to_string());
+        answers.set(2022, 5, 1, "FWSHSPJWM".to_string());
+
+        let toml = toml::to_string(&answers)?;
+        assert!(toml.contains("[2023.07]"), "{toml}");
+        assert_eq!(answers, toml::from_str(&toml)?);
+        assert_eq!(Some("FWSHSPJWM"), answers.get(2022, 5, 1));
+        assert_eq!(None, answers.get(2022, 5, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_doomed() -> Result<()> {
+        let mut answers = Answers::default();
+        answers.reject(2023, 10, 1, "7000".to_string(), Some(Hint::TooHigh));
+        answers.reject(2023, 10, 1, "6000".to_string(), Some(Hint::TooLow));
+        answers.reject(2023, 10, 1, "6500".to_string(), None);
+        answers.reject(2023, 10, 2, "400".to_string(), Some(Hint::TooLow));
+
+        assert_eq!(None, answers.doomed(2023, 10, 1, "6714"));
+        assert!(answers.doomed(2023, 10, 1, "7000").is_some());
+        assert!(answers.doomed(2023, 10, 1, "7100").is_some());
+        assert!(answers.doomed(2023, 10, 1, "5999").is_some());
+        assert!(answers
+            .doomed(2023, 10, 1, "6500")
+            .is_some_and(|why| why.contains("already rejected")));
+        assert_eq!(None, answers.doomed(2023, 10, 2, "429"));
+        assert!(answers.doomed(2023, 10, 2, "300").is_some());
+
+        answers.set(2023, 10, 1, "6714".to_string());
+        assert!(answers.doomed(2023, 10, 1, "6800").is_some());
+
+        let toml = toml::to_string(&answers)?;
+        assert!(toml.contains(r#"hint = "too_high""#), "{toml}");
+        assert_eq!(answers, toml::from_str(&toml)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.set(2023, 7, 1, "252052080".to_string());
+        assert_eq!(Check::Unchanged, answers.check(2023, 7, 1, "252052080"));
+        assert_eq!(
+            Check::Changed {
+                expected: "252052080".to_string()
+            },
+            answers.check(2023, 7, 1, "6440")
+        );
+        assert_eq!(Check::New, answers.check(2023, 7, 2, "252898370"));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::bench::{Report, Stats};
+use aoc_common::{input, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Stores durations as whole nanoseconds rather than serde's `{ secs, nanos }`.
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub p95: Duration,
+}
+
+impl From<Stats> for Timing {
+    fn from(stats: Stats) -> Timing {
+        Timing {
+            min: stats.min,
+            median: stats.median,
+            p95: stats.p95,
+        }
+    }
+}
+
+/// The bench results of one part at one commit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub commit: String,
+    /// Unix time of the run, to tell the latest baseline apart.
+    pub recorded: u64,
+    pub runs: usize,
+    pub parse: Timing,
+    pub solve: Timing,
+}
+
+impl Baseline {
+    pub fn new(year: u16, day: u8, part: u8, commit: &str, report: &Report) -> Result<Baseline> {
+        Ok(Baseline {
+            year,
+            day,
+            part,
+            commit: commit.to_string(),
+            recorded: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            runs: report.runs,
+            parse: report.parse.into(),
+            solve: report.solve.into(),
+        })
+    }
+
+    /// The median time of a whole run, parse and solve.
+    pub fn median(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+
+    /// How much slower than `baseline` this is, in percent, when that is
+    /// more than `threshold` percent.
+    pub fn regression(&self, baseline: &Baseline, threshold: f64) -> Option<f64> {
+        let change =
+            (self.median().as_nanos() as f64 / baseline.median().as_nanos() as f64 - 1.0) * 100.0;
+        (change > threshold).then_some(change)
+    }
+}
+
+/// Every saved bench result, at most one per part and commit.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baselines {
+    #[serde(default, rename = "baseline")]
+    baselines: Vec<Baseline>,
+}
+
+impl Baselines {
+    /// The baselines file, kept out of git as timings only compare on the
+    /// machine they were taken on.
+    pub fn path() -> PathBuf {
+        input::cache_dir().join("benchmarks.toml")
+    }
+
+    /// Loads the baselines at `path`, or none at all when it doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Baselines> {
+        if !path.exists() {
+            return Ok(Baselines::default());
+        }
+        let baselines = fs::read_to_string(path)?;
+        toml::from_str(&baselines).map_err(|error| format!("{}: {error}", path.display()).into())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Adds a baseline, replacing the one of the same part and commit.
+    pub fn record(&mut self, baseline: Baseline) {
+        self.baselines.retain(|old| {
+            (old.year, old.day, old.part, &old.commit)
+                != (baseline.year, baseline.day, baseline.part, &baseline.commit)
+        });
+        self.baselines.push(baseline);
+    }
+
+    /// The baseline of a part to compare against: the one taken at `commit`,
+    /// or else the latest one taken at any commit other than `current`.
+    pub fn find(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        commit: Option<&str>,
+        current: &str,
+    ) -> Option<&Baseline> {
+        self.baselines
+            .iter()
+            .filter(|baseline| (baseline.year, baseline.day, baseline.part) == (year, day, part))
+            .filter(|baseline| match commit {
+                Some(commit) => baseline.commit.starts_with(commit),
+                None => baseline.commit != current,
+            })
+            .max_by_key(|baseline| baseline.recorded)
+    }
+}
+
+/// The commit the repository is at, marked `-dirty` when it has local
+/// changes, or `unknown` outside of git.
+pub fn current_commit(repo: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(repo)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match (
+        git(&["rev-parse", "--short", "HEAD"]),
+        git(&["status", "--porcelain"]),
+    ) {
+        (Some(commit), Some(status)) if !status.is_empty() => format!("{commit}-dirty"),
+        (Some(commit), _) => commit,
+        (None, _) => "unknown".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline(commit: &str, recorded: u64, median: u64) -> Baseline {
+        let timing = |median| Timing {
+            min: Duration::from_micros(median - 1),
+            median: Duration::from_micros(median),
+            p95: Duration::from_micros(median + 1),
+        };
+        Baseline {
+            year: 2023,
+            day: 14,
+            part: 2,
+            commit: commit.to_string(),
+            recorded,
+            runs: 100,
+            parse: timing(10),
+            solve: timing(median),
+        }
+    }
+
+    #[test]
+    fn test_baselines() -> Result<()> {
+        let mut baselines = Baselines::default();
+        baselines.record(baseline("aaaaaaa", 1, 90));
+        baselines.record(baseline("bbbbbbb", 2, 500));
+        baselines.record(baseline("bbbbbbb", 3, 190));
+
+        let toml = toml::to_string(&baselines)?;
+        assert!(toml.contains("[[baseline]]"), "{toml}");
+        assert!(toml.contains("median = 190000"), "{toml}");
+        assert_eq!(baselines, toml::from_str(&toml)?);
+
+        let latest = baselines
+            .find(2023, 14, 2, None, "ccccccc")
+            .expect("should have a baseline");
+        assert_eq!(("bbbbbbb", 3), (latest.commit.as_str(), latest.recorded));
+        let older = baselines
+            .find(2023, 14, 2, None, "bbbbbbb")
+            .expect("should have a baseline");
+        assert_eq!("aaaaaaa", older.commit);
+        assert_eq!(
+            older,
+            baselines
+                .find(2023, 14, 2, Some("aaa"), "bbbbbbb")
+                .expect("should find by prefix")
+        );
+        assert_eq!(None, baselines.find(2023, 14, 1, None, "ccccccc"));
+
+        // 200µs against 100µs, then 200µs against itself
+        assert_eq!(Some(100.0), latest.regression(older, 10.0));
+        assert_eq!(None, latest.regression(latest, 10.0));
+        assert_eq!(None, older.regression(latest, 10.0));
+        Ok(())
+    }
+}
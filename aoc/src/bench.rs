@@ -0,0 +1,118 @@
+use crate::registry::Entry;
+use aoc_common::Result;
+use std::time::{Duration, Instant};
+
+/// How many times to run a solution, and for how long at most.
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    pub warmup: usize,
+    pub runs: usize,
+    /// Stop warming up, or measuring, once this much time has been spent on
+    /// it, so that the slow days still finish. At least one run is measured.
+    pub budget: Duration,
+}
+
+/// The spread of a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, none if there aren't any.
+    pub fn new(mut samples: Vec<Duration>) -> Option<Stats> {
+        samples.sort();
+        // nearest rank, so every statistic is a time that was actually measured
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Some(Stats {
+            min: *samples.first()?,
+            median: percentile(50),
+            p95: percentile(95),
+        })
+    }
+}
+
+/// The timings of one part over every measured run.
+#[derive(Debug)]
+pub struct Report {
+    pub answer: String,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Warms a solution up and then measures its parse and solve separately.
+pub fn bench(entry: &Entry, input: &str, settings: Settings) -> Result<Report> {
+    let start = Instant::now();
+    for _ in 0..settings.warmup {
+        if start.elapsed() >= settings.budget {
+            break;
+        }
+        entry.solve_timed(input)?;
+    }
+
+    let start = Instant::now();
+    let (mut answer, mut parse, mut solve) = (String::new(), vec![], vec![]);
+    while parse.is_empty() || (parse.len() < settings.runs && start.elapsed() < settings.budget) {
+        let timings;
+        (answer, timings) = entry.solve_timed(input)?;
+        parse.push(timings.parse);
+        solve.push(timings.solve);
+    }
+
+    Ok(Report {
+        answer,
+        runs: parse.len(),
+        parse: Stats::new(parse).expect("measured at least once"),
+        solve: Stats::new(solve).expect("measured at least once"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(None, Stats::new(vec![]));
+
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = Stats::new(samples).expect("should have samples");
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(50), stats.median);
+        assert_eq!(Duration::from_millis(95), stats.p95);
+
+        let stats = Stats::new(vec![Duration::from_millis(7)]).expect("should have samples");
+        assert_eq!(Duration::from_millis(7), stats.p95);
+    }
+
+    #[test]
+    fn test_bench() -> Result<()> {
+        let entry = registry::find(2022, 1, 1).expect("should be registered");
+        let input = "1000\n2000\n\n3000\n\n4000\n5000\n";
+        let settings = Settings {
+            warmup: 2,
+            runs: 5,
+            budget: Duration::from_secs(60),
+        };
+        let report = bench(entry, input, settings)?;
+        assert_eq!("9000", report.answer);
+        assert_eq!(5, report.runs);
+        assert!(report.parse.min <= report.parse.median);
+
+        // an exhausted budget still measures once
+        let report = bench(
+            entry,
+            input,
+            Settings {
+                budget: Duration::ZERO,
+                ..settings
+            },
+        )?;
+        assert_eq!(1, report.runs);
+        Ok(())
+    }
+}
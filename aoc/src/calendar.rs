@@ -0,0 +1,276 @@
+use crate::{
+    answers::{Answers, Check},
+    registry::Entry,
+};
+use aoc_common::{alloc, input, Timings};
+use std::{
+    fmt::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// What running one part against its cached input came to.
+#[derive(Debug)]
+pub struct Outcome {
+    pub entry: &'static Entry,
+    pub result: Result<(String, Timings), String>,
+}
+
+impl Outcome {
+    /// Runs a part against its cached input.
+    pub fn run(entry: &'static Entry) -> Outcome {
+        let result = input::Source::Cache(input::cache_path(entry.year, entry.day))
+            .read()
+            .and_then(|input| entry.solve_timed(&input))
+            .map_err(|error| error.to_string());
+        Outcome { entry, result }
+    }
+
+    /// Runs the parts of one day against its cached input, both from a
+    /// single parse when `day` has both.
+    pub fn run_day(day: &[&'static Entry]) -> Vec<Outcome> {
+        let [part1, part2] = day else {
+            return day.iter().map(|entry| Outcome::run(entry)).collect();
+        };
+        let input = input::Source::Cache(input::cache_path(part1.year, part1.day)).read();
+        let results = match input.and_then(|input| part1.solve_day(&input)) {
+            Ok(results) => results.map(|result| result.map_err(|error| error.to_string())),
+            Err(error) => [Err(error.to_string()), Err(error.to_string())],
+        };
+        let [result1, result2] = results;
+        vec![
+            Outcome {
+                entry: part1,
+                result: result1,
+            },
+            Outcome {
+                entry: part2,
+                result: result2,
+            },
+        ]
+    }
+
+    fn time(&self) -> Duration {
+        match &self.result {
+            Ok((_, timings)) => timings.parse + timings.solve,
+            Err(_) => Duration::ZERO,
+        }
+    }
+}
+
+pub fn same_day(a: &Entry, b: &Entry) -> bool {
+    (a.year, a.day) == (b.year, b.day)
+}
+
+/// Runs every entry on up to `jobs` threads, each taking the next day not yet
+/// started and solving its parts from one parse. The outcomes come back
+/// in the order of `entries` however the days finish, and each part's timings
+/// are taken on the thread that ran it.
+pub fn run_all(entries: &[&'static Entry], jobs: usize) -> Vec<Outcome> {
+    let days = entries.chunk_by(|a, b| same_day(a, b)).collect::<Vec<_>>();
+    let next = AtomicUsize::new(0);
+    let finished = Mutex::new((0..days.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(i) else {
+                    break;
+                };
+                let outcomes = Outcome::run_day(day);
+                finished.lock().expect("no worker should panic holding it")[i] = Some(outcomes);
+            });
+        }
+    });
+    finished
+        .into_inner()
+        .expect("no worker should panic holding it")
+        .into_iter()
+        .flat_map(|outcomes| outcomes.expect("every day should have run"))
+        .collect()
+}
+
+/// The totals below the table.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    /// Parts whose answer changed from the accepted one, or that failed.
+    pub failed: usize,
+    /// Parts without an accepted answer to check against.
+    pub unknown: usize,
+    pub over_budget: usize,
+    /// The time of every part added up, whether they ran one after another
+    /// or not.
+    pub total: Duration,
+}
+
+/// Renders a table of every outcome, in order, flagging the days whose parts
+/// took longer than `budget` together. What each part allocated is shown
+/// too when allocations are counted.
+pub fn report(outcomes: &[Outcome], answers: &Answers, budget: Duration) -> (String, Summary) {
+    let memory = |allocations: [String; 2]| match alloc::ENABLED {
+        true => format!("{:>11}  {:>10}  ", allocations[0], allocations[1]),
+        false => String::new(),
+    };
+    let mut table = format!(
+        "{:<11}  {:<16}  {:>9}  {:>9}  {}status\n",
+        "",
+        "answer",
+        "parse",
+        "solve",
+        memory(["allocations".to_string(), "allocated".to_string()])
+    );
+    let mut summary = Summary::default();
+    for day in outcomes.chunk_by(|a, b| same_day(a.entry, b.entry)) {
+        let time = day.iter().map(Outcome::time).sum::<Duration>();
+        summary.total += time;
+        for (i, outcome) in day.iter().enumerate() {
+            let Entry {
+                year,
+                day: dd,
+                part,
+                ..
+            } = *outcome.entry;
+            let (answer, timings, status) = match &outcome.result {
+                Ok((answer, timings)) => {
+                    let status = match answers.check(year, dd, part, answer) {
+                        Check::Unchanged => {
+                            summary.passed += 1;
+                            "ok".to_string()
+                        }
+                        Check::Changed { expected } => {
+                            summary.failed += 1;
+                            format!("WRONG, accepted {expected}")
+                        }
+                        Check::New => {
+                            summary.unknown += 1;
+                            "unknown".to_string()
+                        }
+                    };
+                    (answer.as_str(), Some(timings), status)
+                }
+                Err(error) => {
+                    summary.failed += 1;
+                    ("", None, format!("ERROR {error}"))
+                }
+            };
+            let [parse, solve] = [
+                timings.map(|timings| timings.parse),
+                timings.map(|timings| timings.solve),
+            ]
+            .map(|time| match time {
+                // part 2 shares the parse of part 1
+                Some(time) if time.is_zero() => "-".to_string(),
+                Some(time) => format!("{time:.2?}"),
+                None => String::new(),
+            });
+            let allocations = timings.map(|timings| timings.allocations);
+            let memory = memory([
+                allocations
+                    .map(|allocations| allocations.count.to_string())
+                    .unwrap_or_default(),
+                allocations
+                    .map(|allocations| alloc::Bytes(allocations.bytes).to_string())
+                    .unwrap_or_default(),
+            ]);
+            write!(
+                table,
+                "{year} {dd:02} {part}  {answer:<16}  {parse:>9}  {solve:>9}  {memory}{status}"
+            )
+            .expect("writing to a string");
+            if i == day.len() - 1 && time > budget {
+                summary.over_budget += 1;
+                write!(table, "  OVER BUDGET, day took {time:.2?}").expect("writing to a string");
+            }
+            table.push('\n');
+        }
+    }
+    (table, summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    fn outcome(year: u16, day: u8, part: u8, answer: &str, millis: u64) -> Outcome {
+        let timings = Timings {
+            parse: Duration::from_millis(millis),
+            solve: Duration::from_millis(millis),
+            ..Timings::default()
+        };
+        Outcome {
+            entry: registry::find(year, day, part).expect("should be registered"),
+            result: Ok((answer.to_string(), timings)),
+        }
+    }
+
+    #[test]
+    fn test_run_all() {
+        let entries = registry::SOLUTIONS
+            .iter()
+            .filter(|entry| entry.year == 2022 && entry.day <= 4)
+            .collect::<Vec<_>>();
+        let answers = |outcomes: Vec<Outcome>| {
+            outcomes
+                .into_iter()
+                .map(|outcome| {
+                    let (year, day, part) =
+                        (outcome.entry.year, outcome.entry.day, outcome.entry.part);
+                    (year, day, part, outcome.result.map(|(answer, _)| answer))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let one = answers(run_all(&entries, 1));
+        assert_eq!(8, one.len());
+        assert_eq!(one, answers(run_all(&entries, 3)));
+        assert_eq!(one, answers(run_all(&entries, 64)));
+        assert!(run_all(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_report() {
+        let mut answers = Answers::default();
+        answers.set(2023, 6, 1, "1159152".to_string());
+        answers.set(2023, 6, 2, "41513103".to_string());
+        answers.set(2023, 7, 1, "252052080".to_string());
+        let outcomes = [
+            outcome(2023, 6, 1, "1159152", 1),
+            outcome(2023, 6, 2, "41513103", 300),
+            outcome(2023, 7, 1, "6440", 1),
+            outcome(2023, 7, 2, "252898370", 1),
+            Outcome {
+                entry: registry::find(2023, 8, 1).expect("should be registered"),
+                result: Err("no input".to_string()),
+            },
+        ];
+
+        let (table, summary) = report(&outcomes, &answers, Duration::from_millis(500));
+        assert_eq!(
+            Summary {
+                passed: 2,
+                failed: 2,
+                unknown: 1,
+                over_budget: 1,
+                total: Duration::from_millis(606),
+            },
+            summary
+        );
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(6, lines.len(), "{table}");
+        assert!(lines[1].starts_with("2023 06 1  1159152"), "{table}");
+        assert!(!lines[1].contains("OVER BUDGET"), "{table}");
+        assert!(
+            lines[2].ends_with("OVER BUDGET, day took 602.00ms"),
+            "{table}"
+        );
+        assert!(lines[3].ends_with("WRONG, accepted 252052080"), "{table}");
+        assert!(lines[4].ends_with("unknown"), "{table}");
+        assert!(lines[5].ends_with("ERROR no input"), "{table}");
+    }
+}
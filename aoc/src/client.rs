@@ -0,0 +1,289 @@
+use aoc_common::{input, Result};
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/omegachyll/advent_of_code";
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the last one, with how long is left to wait.
+    RateLimited(Option<String>),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "rate limited, {wait} left"),
+            Verdict::RateLimited(None) => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "wrong level"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site answers a submission with.
+    pub fn parse(html: &str) -> Verdict {
+        if html.contains("That's the right answer") {
+            Verdict::Right
+        } else if html.contains("You gave an answer too recently") {
+            let wait = html.split_once("You have ").and_then(|(_, rest)| {
+                rest.split_once(" left to wait")
+                    .map(|(wait, _)| wait.to_string())
+            });
+            Verdict::RateLimited(wait)
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if html.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if html.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+}
+
+/// Talks to the Advent of Code site, or anything serving the same paths.
+pub struct Client {
+    base_url: String,
+    session: String,
+    cache: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, cache: PathBuf) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            cache,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// A client for `$AOC_BASE_URL` (the real site by default), logged in
+    /// with `$AOC_SESSION` or the token saved in `{cache}/.session`.
+    pub fn from_env() -> Result<Client> {
+        let cache = input::cache_dir();
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => fs::read_to_string(cache.join(".session")).map_err(|_| {
+                format!(
+                    "no session token: set AOC_SESSION or save it to {}",
+                    cache.join(".session").display()
+                )
+            })?,
+        };
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session, cache))
+    }
+
+    fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{year}/day/{day}{page}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Downloads the input of a day into the input cache, unless it is
+    /// already there.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<PathBuf> {
+        let path = input::cache_path_in(&self.cache, year, day);
+        if path.exists() {
+            return Ok(path);
+        }
+        let input = self
+            .agent
+            .get(&self.url(year, day, "/input"))
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, input)?;
+        Ok(path)
+    }
+
+    /// Submits an answer and logs it, with its verdict, to the submission log.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        let page = self
+            .agent
+            .post(&self.url(year, day, "/answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+        let verdict = Verdict::parse(&page);
+        self.log(year, day, part, answer, &verdict)?;
+        Ok(verdict)
+    }
+
+    /// Appends `{unix time}\t{year}\t{day}\t{part}\t{answer}\t{verdict}` to
+    /// `{cache}/submissions.log`.
+    fn log(&self, year: u16, day: u8, part: u8, answer: &str, verdict: &Verdict) -> Result<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let mut log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.cache.join("submissions.log"))?;
+        writeln!(log, "{now}\t{year}\t{day}\t{part}\t{answer}\t{verdict}")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read},
+        net::TcpListener,
+        process, thread,
+    };
+
+    /// Serves one canned page per request on a local port and hands back
+    /// each request's line, cookie and body.
+    fn stub(pages: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("should bind");
+        let base_url = format!(
+            "http://{}",
+            listener.local_addr().expect("should have address")
+        );
+        let server = thread::spawn(move || {
+            pages
+                .into_iter()
+                .map(|page| {
+                    let (mut stream, _) = listener.accept().expect("should accept");
+                    let mut reader = BufReader::new(stream.try_clone().expect("should clone"));
+                    let mut request = String::new();
+                    let mut length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).expect("should read");
+                        let line = line.trim_end();
+                        if line.is_empty() {
+                            break;
+                        }
+                        let lower = line.to_lowercase();
+                        if let Some(value) = lower.strip_prefix("content-length: ") {
+                            length = value.parse().expect("should be a length");
+                        }
+                        if request.is_empty() || lower.starts_with("cookie") {
+                            request.push_str(line);
+                            request.push('\n');
+                        }
+                    }
+                    let mut body = vec![0; length];
+                    reader.read_exact(&mut body).expect("should read body");
+                    request.push_str(&String::from_utf8(body).expect("should be utf8"));
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                        page.len()
+                    )
+                    .expect("should respond");
+                    request
+                })
+                .collect()
+        });
+        (base_url, server)
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |text: &str| format!("<main><article><p>{text}</p></article></main>");
+        assert_eq!(
+            Verdict::Right,
+            Verdict::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            ))
+        );
+        assert_eq!(
+            Verdict::TooHigh,
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too high."
+            ))
+        );
+        assert_eq!(
+            Verdict::TooLow,
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low."
+            ))
+        );
+        assert_eq!(
+            Verdict::Wrong,
+            Verdict::parse(&page("That's not the right answer. If you're stuck, ..."))
+        );
+        assert_eq!(
+            Verdict::RateLimited(Some("4m 12s".to_string())),
+            Verdict::parse(&page(
+                "You gave an answer too recently. You have 4m 12s left to wait."
+            ))
+        );
+        assert_eq!(
+            Verdict::WrongLevel,
+            Verdict::parse(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ))
+        );
+        assert_eq!(Verdict::Unknown, Verdict::parse(""));
+    }
+
+    #[test]
+    fn test_client() -> Result<()> {
+        let cache = env::temp_dir().join(format!("aoc-client-{}", process::id()));
+        fs::create_dir_all(&cache)?;
+        let (base_url, server) = stub(vec![
+            "1 2 3\n",
+            "<p>That's not the right answer; your answer is too low.</p>",
+        ]);
+        let client = Client::new(&base_url, "abc123\n", cache.clone());
+
+        let path = client.fetch_input(2023, 9)?;
+        assert_eq!(cache.join("2023/day-09/input.txt"), path);
+        assert_eq!("1 2 3\n", fs::read_to_string(&path)?);
+        // already cached, so this must not reach the server
+        client.fetch_input(2023, 9)?;
+
+        assert_eq!(Verdict::TooLow, client.submit(2023, 9, 2, "42")?);
+        let log = fs::read_to_string(cache.join("submissions.log"))?;
+        assert!(log.ends_with("\t2023\t9\t2\t42\ttoo low\n"), "{log}");
+
+        let requests = server.join().expect("server should not panic");
+        assert_eq!(
+            "GET /2023/day/9/input HTTP/1.1\nCookie: session=abc123\n",
+            requests[0]
+        );
+        assert_eq!(
+            "POST /2023/day/9/answer HTTP/1.1\nCookie: session=abc123\nlevel=2&answer=42",
+            requests[1]
+        );
+
+        fs::remove_dir_all(cache)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use aoc_common::Result;
+use std::{fs, path::Path};
+
+/// The example and its highlighted answer from one part of a puzzle page.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Decodes the handful of entities a puzzle page uses and drops any markup,
+/// such as the `<em>` highlights inside an example.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The contents between every `open` and the following `close`.
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut found = vec![];
+    let mut rest = 0;
+    while let Some(start) = html[rest..]
+        .find(open)
+        .map(|start| rest + start + open.len())
+    {
+        let Some(end) = html[start..].find(close).map(|end| start + end) else {
+            break;
+        };
+        found.push((start, &html[start..end]));
+        rest = end + close.len();
+    }
+    found
+}
+
+/// Pulls the first `<pre><code>` block and the last highlighted `<code><em>`
+/// (or `<em><code>`) answer out of each part's `<article>` of a saved puzzle page.
+pub fn extract(html: &str) -> Vec<Example> {
+    between(html, "<article", "</article>")
+        .into_iter()
+        .map(|(_, article)| {
+            let input = between(article, "<pre><code>", "</code></pre>")
+                .first()
+                .map(|(_, code)| text(code));
+            let answer = between(article, "<code><em>", "</em></code>")
+                .into_iter()
+                .chain(between(article, "<em><code>", "</code></em>"))
+                .max_by_key(|(position, _)| *position)
+                .map(|(_, answer)| text(answer));
+            Example { input, answer }
+        })
+        .collect()
+}
+
+/// The generated test asserting each part's answer on its example file.
+fn test_file(year: u16, day: u8, parts: &[(u8, &str, &str)]) -> String {
+    let mut test = format!(
+        "// Generated by `aoc examples {year} {day}` from the puzzle page.\n\
+         use aoc_common::solve;\n\
+         use std::fs;\n\
+         use y{year}_day_{day:02}::Day{day:02};\n"
+    );
+    for (part, file, answer) in parts {
+        test.push_str(&format!(
+            "\n#[test]\n\
+             fn part{part}_example() {{\n    \
+                 let input = fs::read_to_string(\"{file}\").expect(\"should be string\");\n    \
+                 let answer = solve::<Day{day:02}>(input.as_str(), {part}).expect(\"should solve\");\n    \
+                 assert_eq!(\"{answer}\", answer);\n\
+             }}\n"
+        ));
+    }
+    test
+}
+
+/// Writes `contents` to `path` unless that would replace something else.
+fn write(path: &Path, contents: &str, force: bool) -> Result<()> {
+    if !force && path.exists() {
+        let existing = fs::read_to_string(path)?;
+        if !existing.is_empty() && existing != contents {
+            return Err(format!(
+                "{} already exists, pass --force to replace it",
+                path.display()
+            )
+            .into());
+        }
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Writes the examples of a puzzle page into the day's folder as
+/// `test_input.txt` (and `test_input2.txt` when part 2 has its own example),
+/// along with `tests/examples.rs` asserting the highlighted answers.
+pub fn write_fixtures(
+    dir: &Path,
+    year: u16,
+    day: u8,
+    html: &str,
+    force: bool,
+) -> Result<Vec<String>> {
+    if !dir.exists() {
+        return Err(format!("{} doesn't exist, create it with `aoc new`", dir.display()).into());
+    }
+    let examples = extract(html);
+    let Some(first) = examples.first().and_then(|example| example.input.as_ref()) else {
+        return Err("no <pre><code> example found in the puzzle page".into());
+    };
+
+    let mut written = vec!["test_input.txt".to_string()];
+    write(&dir.join("test_input.txt"), first, force)?;
+
+    let mut parts = vec![];
+    for (part, example) in (1..=2).zip(&examples) {
+        let file = match &example.input {
+            Some(input) if part == 2 && input != first => {
+                write(&dir.join("test_input2.txt"), input, force)?;
+                written.push("test_input2.txt".to_string());
+                "test_input2.txt"
+            }
+            _ => "test_input.txt",
+        };
+        if let Some(answer) = &example.answer {
+            parts.push((part, file, answer.as_str()));
+        }
+    }
+
+    if !parts.is_empty() {
+        fs::create_dir_all(dir.join("tests"))?;
+        write(
+            &dir.join("tests/examples.rs"),
+            &test_file(year, day, &parts),
+            force,
+        )?;
+        written.push("tests/examples.rs".to_string());
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 9: Mirage Maintenance ---</h2>
+<p>For example:</p>
+<pre><code>0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+</code></pre>
+<p>Extrapolating <em>and</em> summing gives <code>18 + 28 + 68 = <em>114</em></code>.</p>
+<p>The sum is <code><em>114</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>10  13  16  21  30  45
+<em>5</em>  10  13  16  21  30
+</code></pre>
+<p>Adding the new values gives <em><code>2</code></em>.</p>
+<p>Here &lt;this&gt; is &amp; not an answer.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        let examples = extract(PAGE);
+        assert_eq!(2, examples.len());
+        assert_eq!(
+            Some("0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n"),
+            examples[0].input.as_deref()
+        );
+        assert_eq!(Some("114"), examples[0].answer.as_deref());
+        assert_eq!(
+            Some("10  13  16  21  30  45\n5  10  13  16  21  30\n"),
+            examples[1].input.as_deref()
+        );
+        assert_eq!(Some("2"), examples[1].answer.as_deref());
+        assert_eq!("a < b && c", text("a &lt; <em>b</em> &amp;&amp; c"));
+    }
+
+    #[test]
+    fn test_write_fixtures() -> Result<()> {
+        let dir = env::temp_dir().join(format!("aoc-examples-{}", process::id()));
+        assert!(write_fixtures(&dir, 2023, 9, PAGE, false).is_err());
+
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("test_input.txt"), "")?;
+        let written = write_fixtures(&dir, 2023, 9, PAGE, false)?;
+        assert_eq!(
+            vec!["test_input.txt", "test_input2.txt", "tests/examples.rs"],
+            written
+        );
+        let test = fs::read_to_string(dir.join("tests/examples.rs"))?;
+        assert!(test.contains("use y2023_day_09::Day09;"));
+        assert!(test.contains(r#"assert_eq!("114", answer);"#));
+        assert!(test.contains(r#"fs::read_to_string("test_input2.txt")"#));
+
+        fs::write(dir.join("test_input.txt"), "hand written")?;
+        assert!(write_fixtures(&dir, 2023, 9, PAGE, false).is_err());
+        write_fixtures(&dir, 2023, 9, PAGE, true)?;
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
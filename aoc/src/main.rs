@@ -0,0 +1,491 @@
+use answers::{Answers, Check, Hint};
+use aoc_common::{alloc, input, trace};
+use baseline::{Baseline, Baselines};
+use clap::{Parser, Subcommand};
+use client::{Client, Verdict};
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+use tracing::Level;
+
+mod answers;
+mod baseline;
+mod bench;
+mod calendar;
+mod client;
+mod examples;
+mod registry;
+mod scaffold;
+
+/// Runs any Advent of Code solution in this repository.
+#[derive(Parser, Debug)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print logs on stderr up to this level (error, warn, info, debug or
+    /// trace), in place of what RUST_LOG asks for
+    #[arg(long, global = true, value_name = "LEVEL")]
+    trace: Option<Level>,
+    /// Also print the logs of a year, day or module of a day, as
+    /// YEAR[/DAY[/MODULE]][=LEVEL] (every level when not given), or any
+    /// RUST_LOG directive
+    #[arg(long, global = true, value_name = "FILTER", value_delimiter = ',')]
+    log: Vec<String>,
+    /// Log only the first this many steps of each hot loop
+    #[arg(long, global = true, value_name = "N")]
+    steps: Option<usize>,
+    /// Write a chrome trace of every span to this file, to open in
+    /// chrome://tracing or ui.perfetto.dev
+    #[arg(long, global = true, value_name = "PATH")]
+    chrome_trace: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run a single part of a single day, or with `--all` every part of
+    /// every day
+    Run {
+        #[arg(required_unless_present = "all")]
+        year: Option<u16>,
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        #[arg(
+            required_unless_present = "all",
+            value_parser = clap::value_parser!(u8).range(1..=2)
+        )]
+        part: Option<u8>,
+        /// Read the input from this file, or `-` for stdin, instead of
+        /// whatever is piped in or the input cache
+        input: Option<PathBuf>,
+        /// Run every day against its cached input and check the answers
+        #[arg(long, conflicts_with_all = ["year", "day", "part", "input"])]
+        all: bool,
+        /// Only run the days of this year with `--all`
+        #[arg(long = "year", short = 'y', value_name = "YEAR", requires = "all")]
+        only_year: Option<u16>,
+        /// Milliseconds a day may take, both parts together, before it is
+        /// flagged
+        #[arg(long, default_value_t = 1000, requires = "all")]
+        budget: u64,
+        /// The known answers to check against, instead of `answers.toml`
+        #[arg(long, requires = "all")]
+        answers: Option<PathBuf>,
+        /// How many days to run at once with `--all`, 0 for one per CPU
+        #[arg(long, short = 'j', default_value_t = 1, requires = "all")]
+        jobs: usize,
+    },
+    /// Rerun every solution against its cached input and flag any answer
+    /// that differs from the accepted one
+    Verify {
+        #[arg(short, long)]
+        year: Option<u16>,
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Store answers for parts that don't have an accepted answer yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time every solution over many runs, parse and solve separately
+    Bench {
+        #[arg(short, long)]
+        year: Option<u16>,
+        #[arg(short, long)]
+        day: Option<u8>,
+        #[arg(short, long)]
+        part: Option<u8>,
+        /// Unmeasured runs before timing
+        #[arg(long, default_value_t = 10)]
+        warmup: usize,
+        /// Measured runs
+        #[arg(long, default_value_t = 100)]
+        runs: usize,
+        /// Seconds each part may spend on warming up, and again on measured
+        /// runs, before stopping early
+        #[arg(long, default_value_t = 5.0)]
+        budget: f64,
+        /// Flag parts that got slower than their baseline at this commit, or
+        /// by default at the latest other commit that was benched
+        #[arg(long, value_name = "COMMIT")]
+        compare: Option<Option<String>>,
+        /// How many percent slower than its baseline a part may get
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download a day's input into the input cache
+    Fetch { year: u16, day: u8 },
+    /// Submit an answer, by default the one the solution computes
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: Option<String>,
+    },
+    /// Extract the examples and their answers from a saved puzzle page into
+    /// the day's example files and a generated test
+    Examples {
+        year: u16,
+        day: u8,
+        /// The puzzle page, as saved from the browser
+        page: PathBuf,
+        /// Replace example files that already have other contents
+        #[arg(long)]
+        force: bool,
+    },
+    /// Create a new day from the template
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn run(year: u16, day: u8, part: u8, path: Option<PathBuf>) -> Result<(), Box<dyn Error>> {
+    let entry = registry::find(year, day, part)
+        .ok_or_else(|| format!("no solution for {year} day {day} part {part}"))?;
+
+    let now = Instant::now();
+    let input = input::read(year, day, path)?;
+    let (answer, timings) = entry.solve_timed(input.as_str())?;
+    println!("{answer:?}");
+    let elapsed = now.elapsed();
+    println!("Elapsed : {:.2?}", elapsed);
+    alloc::report(timings.allocations);
+    Ok(())
+}
+
+fn run_all(
+    year: Option<u16>,
+    budget: Duration,
+    answers: &Path,
+    jobs: usize,
+) -> Result<(), Box<dyn Error>> {
+    let answers = Answers::load(answers)?;
+    let entries = registry::SOLUTIONS
+        .iter()
+        .filter(|entry| year.is_none_or(|year| entry.year == year))
+        .collect::<Vec<_>>();
+    let jobs = match jobs {
+        0 => thread::available_parallelism()?.get(),
+        jobs => jobs,
+    };
+
+    let now = Instant::now();
+    let outcomes = calendar::run_all(&entries, jobs);
+    let elapsed = now.elapsed();
+
+    let (table, summary) = calendar::report(&outcomes, &answers, budget);
+    print!("{table}");
+    println!(
+        "{} ok, {} failed, {} unknown, {} days over {budget:.2?}",
+        summary.passed, summary.failed, summary.unknown, summary.over_budget
+    );
+    println!("Total : {:.2?} (Elapsed : {elapsed:.2?})", summary.total);
+    if let Some(peak) = alloc::peak_resident().filter(|_| alloc::ENABLED) {
+        println!("Peak resident : {peak}");
+    }
+    match summary.failed {
+        0 => Ok(()),
+        failed => Err(format!("{failed} answers changed or failed").into()),
+    }
+}
+
+fn verify(year: Option<u16>, day: Option<u8>, record: bool) -> Result<(), Box<dyn Error>> {
+    let path = Answers::path();
+    let mut answers = Answers::load(&path)?;
+    let mut failures = 0;
+
+    let entries = registry::SOLUTIONS
+        .iter()
+        .filter(|entry| {
+            year.is_none_or(|year| entry.year == year) && day.is_none_or(|day| entry.day == day)
+        })
+        .collect::<Vec<_>>();
+    let outcomes = entries
+        .chunk_by(|a, b| calendar::same_day(a, b))
+        .flat_map(calendar::Outcome::run_day);
+    for calendar::Outcome { entry, result } in outcomes {
+        let (year, day, part) = (entry.year, entry.day, entry.part);
+        let status = match result.map(|(answer, _)| answer) {
+            Ok(answer) => match answers.check(year, day, part, &answer) {
+                Check::Unchanged => format!("ok       {answer}"),
+                Check::Changed { expected } => {
+                    failures += 1;
+                    format!("CHANGED  {answer} (accepted {expected})")
+                }
+                Check::New if record => {
+                    answers.set(year, day, part, answer.clone());
+                    format!("recorded {answer}")
+                }
+                Check::New => format!("new      {answer}"),
+            },
+            Err(error) => {
+                failures += 1;
+                format!("ERROR    {error}")
+            }
+        };
+        println!("{year} {day:02} {part}  {status}");
+    }
+
+    if record {
+        answers.save(&path)?;
+    }
+    match failures {
+        0 => Ok(()),
+        failures => Err(format!("{failures} answers changed or failed").into()),
+    }
+}
+
+fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    settings: bench::Settings,
+    compare: Option<Option<String>>,
+    threshold: f64,
+) -> Result<(), Box<dyn Error>> {
+    if cfg!(debug_assertions) {
+        eprintln!("Benchmarking a debug build, which won't be saved as a baseline");
+        eprintln!("Run it with `cargo run --release` instead");
+    }
+    let path = Baselines::path();
+    let mut baselines = Baselines::load(&path)?;
+    let commit = baseline::current_commit(&repo_root()?);
+    let mut regressions = 0;
+    let mut failures = 0;
+
+    println!(
+        "{:<11} {:>29}   {:>29}   {:>5}   answer",
+        "", "parse min / median / p95", "solve min / median / p95", "runs"
+    );
+    for entry in registry::SOLUTIONS.iter().filter(|entry| {
+        year.is_none_or(|year| entry.year == year)
+            && day.is_none_or(|day| entry.day == day)
+            && part.is_none_or(|part| entry.part == part)
+    }) {
+        let report = input::Source::Cache(input::cache_path(entry.year, entry.day))
+            .read()
+            .and_then(|input| bench::bench(entry, &input, settings));
+        let report = match report {
+            Ok(report) => report,
+            Err(error) => {
+                failures += 1;
+                println!(
+                    "{} {:02} {}   ERROR {error}",
+                    entry.year, entry.day, entry.part
+                );
+                continue;
+            }
+        };
+        let stats = |stats: bench::Stats| {
+            format!(
+                "{:>9} {:>9} {:>9}",
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95)
+            )
+        };
+        println!(
+            "{} {:02} {}   {}   {}   {:>5}   {}",
+            entry.year,
+            entry.day,
+            entry.part,
+            stats(report.parse),
+            stats(report.solve),
+            report.runs,
+            report.answer
+        );
+
+        let current = Baseline::new(entry.year, entry.day, entry.part, &commit, &report)?;
+        if let Some(against) = &compare {
+            let baseline = baselines.find(
+                entry.year,
+                entry.day,
+                entry.part,
+                against.as_deref(),
+                &commit,
+            );
+            match baseline {
+                Some(baseline) => {
+                    if let Some(change) = current.regression(baseline, threshold) {
+                        regressions += 1;
+                        println!(
+                            "            SLOWER by {change:.0}% than {:.2?} at {}",
+                            baseline.median(),
+                            baseline.commit
+                        );
+                    }
+                }
+                None => println!("            no baseline to compare with"),
+            }
+        }
+        if !cfg!(debug_assertions) {
+            baselines.record(current);
+        }
+    }
+
+    if !cfg!(debug_assertions) {
+        baselines.save(&path)?;
+    }
+    match (failures, regressions) {
+        (0, 0) => Ok(()),
+        (0, regressions) => {
+            Err(format!("{regressions} parts got slower than their baseline").into())
+        }
+        (failures, 0) => Err(format!("{failures} parts failed").into()),
+        (failures, regressions) => Err(format!(
+            "{failures} parts failed and {regressions} got slower than their baseline"
+        )
+        .into()),
+    }
+}
+
+fn fetch(year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    let path = Client::from_env()?.fetch_input(year, day)?;
+    println!("Saved {}", path.display());
+    Ok(())
+}
+
+fn submit(year: u16, day: u8, part: u8, answer: Option<String>) -> Result<(), Box<dyn Error>> {
+    let client = Client::from_env()?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let entry = registry::find(year, day, part)
+                .ok_or_else(|| format!("no solution for {year} day {day} part {part}"))?;
+            entry.solve(&input::read(year, day, None)?)?
+        }
+    };
+
+    let path = Answers::path();
+    let mut answers = Answers::load(&path)?;
+    if let Some(why) = answers.doomed(year, day, part, &answer) {
+        return Err(format!("not submitting {answer}: {why}").into());
+    }
+
+    let verdict = client.submit(year, day, part, &answer)?;
+    println!("{answer}: {verdict}");
+    match verdict {
+        Verdict::Right => answers.set(year, day, part, answer),
+        Verdict::TooHigh => answers.reject(year, day, part, answer, Some(Hint::TooHigh)),
+        Verdict::TooLow => answers.reject(year, day, part, answer, Some(Hint::TooLow)),
+        Verdict::Wrong => answers.reject(year, day, part, answer, None),
+        _ => return Ok(()),
+    }
+    answers.save(&path)
+}
+
+/// The root of this repository, where every `{year}/day-{dd}` crate lives.
+fn repo_root() -> Result<PathBuf, Box<dyn Error>> {
+    Ok(Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .canonicalize()?)
+}
+
+fn new(year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    let dir = scaffold::create(&repo_root()?, year, day)?;
+    println!("Created {}", dir.display());
+    println!("Register it in aoc/Cargo.toml and aoc/src/registry.rs to run it with `aoc run`");
+    Ok(())
+}
+
+fn extract_examples(year: u16, day: u8, page: &Path, force: bool) -> Result<(), Box<dyn Error>> {
+    let dir = repo_root()?
+        .join(year.to_string())
+        .join(format!("day-{day:02}"));
+    let html = fs::read_to_string(page)?;
+    for file in examples::write_fixtures(&dir, year, day, &html, force)? {
+        println!("Wrote {}", dir.join(file).display());
+    }
+    Ok(())
+}
+
+#[tracing::instrument]
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let settings = trace::Settings {
+        level: cli.trace,
+        filters: cli.log,
+        steps: cli.steps,
+        chrome: cli.chrome_trace,
+    };
+    // the guard is held until the command is done, to finish the chrome trace
+    let result = trace::init(settings).and_then(|_trace| dispatch(cli.command));
+    // printed with Display rather than the Debug of returning it from main,
+    // so that a parse error shows the line it is on
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn dispatch(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Run {
+            all: true,
+            only_year,
+            budget,
+            answers,
+            jobs,
+            ..
+        } => run_all(
+            only_year,
+            Duration::from_millis(budget),
+            &answers.unwrap_or_else(Answers::path),
+            jobs,
+        ),
+        Command::Run {
+            year: Some(year),
+            day: Some(day),
+            part: Some(part),
+            input,
+            ..
+        } => run(year, day, part, input),
+        Command::Run { .. } => unreachable!("clap requires a year, day and part without --all"),
+        Command::Verify { year, day, record } => verify(year, day, record),
+        Command::Bench {
+            year,
+            day,
+            part,
+            warmup,
+            runs,
+            budget,
+            compare,
+            threshold,
+        } => bench(
+            year,
+            day,
+            part,
+            bench::Settings {
+                warmup,
+                runs,
+                budget: Duration::from_secs_f64(budget),
+            },
+            compare,
+            threshold,
+        ),
+        Command::Fetch { year, day } => fetch(year, day),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => submit(year, day, part, answer),
+        Command::Examples {
+            year,
+            day,
+            page,
+            force,
+        } => extract_examples(year, day, &page, force),
+        Command::New { year, day } => new(year, day),
+    }
+}
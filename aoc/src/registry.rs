@@ -0,0 +1,118 @@
+use aoc_common::{Result, Timings};
+
+/// Parses an input and solves the given part of it, timing both steps.
+pub type Solve = fn(&str, u8) -> Result<(String, Timings)>;
+
+/// Parses an input once and solves both parts of it, see
+/// [`aoc_common::solve_day`].
+pub type SolveDay = fn(&str) -> Result<[Result<(String, Timings)>; 2]>;
+
+#[derive(Debug)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    solve: Solve,
+    solve_day: SolveDay,
+}
+
+impl Entry {
+    pub fn solve(&self, input: &str) -> Result<String> {
+        self.solve_timed(input).map(|(answer, _)| answer)
+    }
+
+    pub fn solve_timed(&self, input: &str) -> Result<(String, Timings)> {
+        (self.solve)(input, self.part)
+    }
+
+    /// Both parts of this entry's day, from one parse.
+    pub fn solve_day(&self, input: &str) -> Result<[Result<(String, Timings)>; 2]> {
+        (self.solve_day)(input)
+    }
+}
+
+macro_rules! entry {
+    ($year:literal, $day:literal, $part:literal, $solution:ty) => {
+        Entry {
+            year: $year,
+            day: $day,
+            part: $part,
+            solve: aoc_common::solve_timed::<$solution>,
+            solve_day: aoc_common::solve_day::<$solution>,
+        }
+    };
+}
+
+pub const SOLUTIONS: &[Entry] = &[
+    entry!(2022, 1, 1, y2022_day_01::Day01),
+    entry!(2022, 1, 2, y2022_day_01::Day01),
+    entry!(2022, 2, 1, y2022_day_02::Day02),
+    entry!(2022, 2, 2, y2022_day_02::Day02),
+    entry!(2022, 3, 1, y2022_day_03::Day03),
+    entry!(2022, 3, 2, y2022_day_03::Day03),
+    entry!(2022, 4, 1, y2022_day_04::Day04),
+    entry!(2022, 4, 2, y2022_day_04::Day04),
+    entry!(2022, 5, 1, y2022_day_05::Day05),
+    entry!(2022, 5, 2, y2022_day_05::Day05),
+    entry!(2022, 6, 1, y2022_day_06::Day06),
+    entry!(2022, 6, 2, y2022_day_06::Day06),
+    entry!(2023, 1, 1, y2023_day_01::Day01),
+    entry!(2023, 1, 2, y2023_day_01::Day01),
+    entry!(2023, 2, 1, y2023_day_02::Day02),
+    entry!(2023, 2, 2, y2023_day_02::Day02),
+    entry!(2023, 3, 1, y2023_day_03::Day03),
+    entry!(2023, 3, 2, y2023_day_03::Day03),
+    entry!(2023, 4, 1, y2023_day_04::Day04),
+    entry!(2023, 4, 2, y2023_day_04::Day04),
+    entry!(2023, 5, 1, y2023_day_05::Day05),
+    entry!(2023, 5, 2, y2023_day_05::Day05),
+    entry!(2023, 6, 1, y2023_day_06::Day06),
+    entry!(2023, 6, 2, y2023_day_06::Day06),
+    entry!(2023, 7, 1, y2023_day_07::Day07),
+    entry!(2023, 7, 2, y2023_day_07::Day07),
+    entry!(2023, 8, 1, y2023_day_08::Day08),
+    entry!(2023, 8, 2, y2023_day_08::Day08),
+    entry!(2023, 9, 1, y2023_day_09::Day09),
+    entry!(2023, 9, 2, y2023_day_09::Day09),
+    entry!(2023, 10, 1, y2023_day_10::Day10),
+    entry!(2023, 10, 2, y2023_day_10::Day10),
+    entry!(2023, 11, 1, y2023_day_11::Day11),
+    entry!(2023, 11, 2, y2023_day_11::Day11),
+    entry!(2023, 12, 1, y2023_day_12::Day12),
+    entry!(2023, 12, 2, y2023_day_12::Day12),
+    entry!(2023, 13, 1, y2023_day_13::Day13),
+    entry!(2023, 13, 2, y2023_day_13::Day13),
+    entry!(2023, 14, 1, y2023_day_14::Day14),
+    entry!(2023, 14, 2, y2023_day_14::Day14),
+    entry!(2023, 15, 1, y2023_day_15::Day15),
+    entry!(2023, 15, 2, y2023_day_15::Day15),
+    entry!(2023, 16, 1, y2023_day_16::Day16),
+    entry!(2023, 16, 2, y2023_day_16::Day16),
+];
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Entry> {
+    SOLUTIONS
+        .iter()
+        .find(|entry| entry.year == year && entry.day == day && entry.part == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input;
+
+    #[test]
+    fn test_find() {
+        let entry = find(2023, 10, 2).expect("should be registered");
+        assert_eq!((2023, 10, 2), (entry.year, entry.day, entry.part));
+        assert!(find(2023, 26, 1).is_none());
+    }
+
+    #[test]
+    fn test_inputs_exist() {
+        for entry in SOLUTIONS {
+            let path = input::cache_path(entry.year, entry.day);
+            assert!(path.exists(), "{path:?}");
+        }
+    }
+}
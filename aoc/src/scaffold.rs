@@ -0,0 +1,91 @@
+use aoc_common::Result;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Every file of a new day, relative to its directory, and the template it
+/// is rendered from.
+const TEMPLATE: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../../2023/template/Cargo.toml")),
+    ("src/lib.rs", include_str!("../../2023/template/src/lib.rs")),
+    (
+        "src/part1.rs",
+        include_str!("../../2023/template/src/part1.rs"),
+    ),
+    (
+        "src/part2.rs",
+        include_str!("../../2023/template/src/part2.rs"),
+    ),
+    (
+        "src/bin/part1.rs",
+        include_str!("../../2023/template/src/bin/part1.rs"),
+    ),
+    (
+        "src/bin/part2.rs",
+        include_str!("../../2023/template/src/bin/part2.rs"),
+    ),
+    ("input.txt", ""),
+    ("test_input.txt", ""),
+];
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{dd}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Creates `{root}/{year}/day-{dd}` from the template, refusing to touch a
+/// day that already exists.
+pub fn create(root: &Path, year: u16, day: u8) -> Result<PathBuf> {
+    let dir = root.join(year.to_string()).join(format!("day-{day:02}"));
+    fs::create_dir_all(root.join(year.to_string()))?;
+    fs::create_dir(&dir).map_err(|error| match error.kind() {
+        io::ErrorKind::AlreadyExists => format!("{} already exists", dir.display()).into(),
+        _ => Box::<dyn std::error::Error>::from(error),
+    })?;
+
+    for (path, template) in TEMPLATE {
+        let path = dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, render(template, year, day))?;
+    }
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn test_create() -> Result<()> {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let dir = create(&root, 2024, 7)?;
+        assert_eq!(root.join("2024/day-07"), dir);
+
+        let manifest = fs::read_to_string(dir.join("Cargo.toml"))?;
+        assert!(manifest.contains(r#"name = "y2024-day-07""#));
+        assert!(manifest.contains(r#"name = "y2024-day-07-part2""#));
+        let lib = fs::read_to_string(dir.join("src/lib.rs"))?;
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("const DAY: u8 = 7;"));
+        for (path, _) in TEMPLATE {
+            assert!(
+                !fs::read_to_string(dir.join(path))?.contains("{{"),
+                "{path}"
+            );
+        }
+        assert_eq!("", fs::read_to_string(dir.join("test_input.txt"))?);
+
+        fs::write(dir.join("input.txt"), "my input")?;
+        assert!(create(&root, 2024, 7).is_err());
+        assert_eq!("my input", fs::read_to_string(dir.join("input.txt"))?);
+
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+}
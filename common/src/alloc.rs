@@ -0,0 +1,187 @@
+//! Counting what the solutions allocate. Nothing is counted unless built with
+//! the `count-allocations` feature, as in
+//! `cargo run -p aoc --features count-allocations -- run 2023 14 2`, which
+//! makes [`Counting`] the global allocator of whatever links this crate.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::{self, Display},
+    fs,
+};
+
+/// Whether allocations are being counted at all.
+pub const ENABLED: bool = cfg!(feature = "count-allocations");
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+thread_local! {
+    // const, so that using them never allocates itself
+    static COUNT: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting every allocation on the thread making it.
+/// Counting per thread keeps the days run side by side apart.
+pub struct Counting;
+
+impl Counting {
+    fn record(size: usize) {
+        // a thread being torn down can't count any more, which is fine
+        let _ = COUNT.try_with(|count| count.set(count.get() + 1));
+        let _ = BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Counting::record(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Counting::record(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    /// Counts as an allocation of the whole new size, as growing a `Vec`
+    /// often has to copy it all anyway.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Counting::record(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// How many allocations were made and how many bytes they came to, all zero
+/// unless [`ENABLED`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+}
+
+impl Allocations {
+    /// Everything this thread has allocated so far.
+    pub fn now() -> Allocations {
+        Allocations {
+            count: COUNT.with(Cell::get),
+            bytes: BYTES.with(Cell::get),
+        }
+    }
+
+    /// What this thread has allocated since `before` was taken.
+    pub fn since(before: Allocations) -> Allocations {
+        let now = Allocations::now();
+        Allocations {
+            count: now.count - before.count,
+            bytes: now.bytes - before.bytes,
+        }
+    }
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} allocations, {}", self.count, Bytes(self.bytes))
+    }
+}
+
+/// A number of bytes in B, KiB, MiB or GiB, whichever reads best.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut size = self.0 as f64;
+        for unit in ["B", "KiB", "MiB"] {
+            if size < 1024.0 {
+                return match unit {
+                    "B" => write!(f, "{} B", self.0),
+                    unit => write!(f, "{size:.1} {unit}"),
+                };
+            }
+            size /= 1024.0;
+        }
+        write!(f, "{size:.1} GiB")
+    }
+}
+
+/// The most memory the process has had resident at once, where the system
+/// tells (Linux does, in `/proc/self/status`).
+pub fn peak_resident() -> Option<Bytes> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(Bytes(kilobytes * 1024))
+}
+
+/// Prints what a solution allocated, as in its [`crate::Timings`], and the
+/// peak resident memory, under the `Elapsed` of a binary, when [`ENABLED`].
+pub fn report(allocations: Allocations) {
+    if !ENABLED {
+        return;
+    }
+    match peak_resident() {
+        Some(peak) => println!("Allocated : {allocations}, peak resident {peak}"),
+        None => println!("Allocated : {allocations}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting() {
+        let layout = Layout::from_size_align(100, 8).expect("should be a layout");
+        let before = Allocations::now();
+        unsafe {
+            let ptr = Counting.alloc(layout);
+            assert!(!ptr.is_null());
+            let ptr = Counting.realloc(ptr, layout, 300);
+            Counting.dealloc(
+                ptr,
+                Layout::from_size_align(300, 8).expect("should be a layout"),
+            );
+        }
+        let after = Allocations::since(before);
+        // the global allocator may have counted more in between, when enabled
+        assert!(after.count >= 2 && after.bytes >= 400, "{after:?}");
+        if !ENABLED {
+            assert_eq!(
+                Allocations {
+                    count: 2,
+                    bytes: 400
+                },
+                after
+            );
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!("512 B", Bytes(512).to_string());
+        assert_eq!("1.5 KiB", Bytes(1536).to_string());
+        assert_eq!("12.0 MiB", Bytes(12 * 1024 * 1024).to_string());
+        assert_eq!("2.0 GiB", Bytes(2 << 30).to_string());
+        assert_eq!(
+            "3 allocations, 2.0 KiB",
+            Allocations {
+                count: 3,
+                bytes: 2048
+            }
+            .to_string()
+        );
+    }
+}
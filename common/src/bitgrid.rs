@@ -0,0 +1,179 @@
+//! Grids of one bit a tile, for the sets of visited tiles that hot loops
+//! would otherwise hash positions into.
+
+use crate::Dir4;
+use glam::IVec2;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of the positions on a grid, a bit each, row after row in words of
+/// 64 so that counting and joining sets go a word at a time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// A grid of the given size with nothing set.
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        BitGrid {
+            width,
+            height,
+            words: vec![0; (width * height).div_ceil(BITS)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: IVec2) -> bool {
+        (0..self.width as i32).contains(&position.x)
+            && (0..self.height as i32).contains(&position.y)
+    }
+
+    fn bit(&self, position: IVec2) -> Option<(usize, u64)> {
+        self.contains(position).then(|| {
+            let index = position.y as usize * self.width + position.x as usize;
+            (index / BITS, 1 << (index % BITS))
+        })
+    }
+
+    /// Sets `position`, saying whether it wasn't set already, as
+    /// `HashSet::insert` does.
+    pub fn set(&mut self, position: IVec2) -> bool {
+        let (word, bit) = self.bit(position).unwrap_or_else(|| {
+            panic!("{position} is off a grid {} by {}", self.width, self.height)
+        });
+        let unset = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        unset
+    }
+
+    /// Whether `position` is set, never off the edge of the grid.
+    pub fn test(&self, position: IVec2) -> bool {
+        self.bit(position)
+            .is_some_and(|(word, bit)| self.words[word] & bit != 0)
+    }
+
+    /// How many positions are set.
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Sets every position set in `other`, a grid of the same size.
+    pub fn union(&mut self, other: &BitGrid) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "grids of different sizes"
+        );
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    /// Every position set, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, word)| {
+                let mut word = *word;
+                std::iter::from_fn(move || {
+                    (word != 0).then(|| {
+                        let bit = index * BITS + word.trailing_zeros() as usize;
+                        word &= word - 1;
+                        IVec2::new((bit % self.width) as i32, (bit / self.width) as i32)
+                    })
+                })
+            })
+    }
+}
+
+/// A set of the positions on a grid and the directions they were passed
+/// through in, a [`BitGrid`] for each direction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DirBitGrid {
+    planes: [BitGrid; 4],
+}
+
+impl DirBitGrid {
+    pub fn new(width: usize, height: usize) -> DirBitGrid {
+        DirBitGrid {
+            planes: Dir4::ALL.map(|_| BitGrid::new(width, height)),
+        }
+    }
+
+    /// Sets `position` going `direction`, saying whether it wasn't set
+    /// already.
+    pub fn set(&mut self, position: IVec2, direction: Dir4) -> bool {
+        self.planes[direction as usize].set(position)
+    }
+
+    pub fn test(&self, position: IVec2, direction: Dir4) -> bool {
+        self.planes[direction as usize].test(position)
+    }
+
+    /// How many positions and directions are set.
+    pub fn count(&self) -> usize {
+        self.planes.iter().map(BitGrid::count).sum()
+    }
+
+    /// The positions set going any direction.
+    pub fn cells(&self) -> BitGrid {
+        let [first, rest @ ..] = &self.planes;
+        rest.iter().fold(first.clone(), |mut cells, plane| {
+            cells.union(plane);
+            cells
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_grid() {
+        // wider than a word, so that rows straddle them
+        let mut grid = BitGrid::new(70, 3);
+        assert!(grid.set(IVec2::new(0, 0)));
+        assert!(grid.set(IVec2::new(69, 1)));
+        assert!(!grid.set(IVec2::new(69, 1)));
+        assert!(grid.test(IVec2::new(69, 1)));
+        assert!(!grid.test(IVec2::new(68, 1)));
+        assert!(!grid.test(IVec2::new(70, 0)));
+        assert_eq!(2, grid.count());
+
+        let mut other = BitGrid::new(70, 3);
+        other.set(IVec2::new(0, 0));
+        other.set(IVec2::new(5, 2));
+        grid.union(&other);
+        assert_eq!(
+            vec![IVec2::new(0, 0), IVec2::new(69, 1), IVec2::new(5, 2)],
+            grid.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_dir_bit_grid() {
+        let mut grid = DirBitGrid::new(4, 4);
+        assert!(grid.set(IVec2::new(1, 2), Dir4::East));
+        assert!(grid.set(IVec2::new(1, 2), Dir4::North));
+        assert!(!grid.set(IVec2::new(1, 2), Dir4::East));
+        assert!(!grid.test(IVec2::new(1, 2), Dir4::West));
+        assert!(grid.set(IVec2::new(3, 3), Dir4::West));
+        assert_eq!(3, grid.count());
+        assert_eq!(2, grid.cells().count());
+    }
+}
@@ -0,0 +1,182 @@
+//! The directions to move in on a grid, with north up the page, towards
+//! smaller `y`.
+
+use glam::IVec2;
+
+/// One of the four directions along the rows and columns of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// The direction a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Dir4 {
+        match self {
+            Dir4::North => Dir4::West,
+            Dir4::East => Dir4::North,
+            Dir4::South => Dir4::East,
+            Dir4::West => Dir4::South,
+        }
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Dir4 {
+        match self {
+            Dir4::North => Dir4::East,
+            Dir4::East => Dir4::South,
+            Dir4::South => Dir4::West,
+            Dir4::West => Dir4::North,
+        }
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        match self {
+            Dir4::North => Dir4::South,
+            Dir4::East => Dir4::West,
+            Dir4::South => Dir4::North,
+            Dir4::West => Dir4::East,
+        }
+    }
+
+    /// The direction a beam going this way leaves a `/` or `\` mirror in.
+    ///
+    /// # Panics
+    ///
+    /// If `mirror` is any other character.
+    pub fn reflect(self, mirror: char) -> Dir4 {
+        match (mirror, self) {
+            ('/', Dir4::North | Dir4::South) | ('\\', Dir4::East | Dir4::West) => self.turn_right(),
+            ('/', Dir4::East | Dir4::West) | ('\\', Dir4::North | Dir4::South) => self.turn_left(),
+            _ => panic!("{mirror:?} is not a mirror"),
+        }
+    }
+
+    /// The step of one tile this way.
+    pub fn to_ivec2(self) -> IVec2 {
+        match self {
+            Dir4::North => IVec2::NEG_Y,
+            Dir4::East => IVec2::X,
+            Dir4::South => IVec2::Y,
+            Dir4::West => IVec2::NEG_X,
+        }
+    }
+}
+
+/// One of the eight directions to the tiles around one, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    fn turn(self, eighths: usize) -> Dir8 {
+        Dir8::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// The direction an eighth of a turn anticlockwise.
+    pub fn turn_left(self) -> Dir8 {
+        self.turn(7)
+    }
+
+    /// The direction an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Dir8 {
+        self.turn(1)
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        self.turn(4)
+    }
+
+    /// The step of one tile this way, diagonals being one across and one
+    /// up or down.
+    pub fn to_ivec2(self) -> IVec2 {
+        match self {
+            Dir8::North => IVec2::NEG_Y,
+            Dir8::NorthEast => IVec2::new(1, -1),
+            Dir8::East => IVec2::X,
+            Dir8::SouthEast => IVec2::ONE,
+            Dir8::South => IVec2::Y,
+            Dir8::SouthWest => IVec2::new(-1, 1),
+            Dir8::West => IVec2::NEG_X,
+            Dir8::NorthWest => IVec2::NEG_ONE,
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(direction: Dir4) -> Dir8 {
+        match direction {
+            Dir4::North => Dir8::North,
+            Dir4::East => Dir8::East,
+            Dir4::South => Dir8::South,
+            Dir4::West => Dir8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir4() {
+        for direction in Dir4::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.opposite(), direction.turn_right().turn_right());
+            assert_eq!(
+                IVec2::ZERO,
+                direction.to_ivec2() + direction.opposite().to_ivec2()
+            );
+            assert_eq!(direction.to_ivec2(), Dir8::from(direction).to_ivec2());
+            // a beam bounces back the way it came off both mirrors in turn
+            assert_eq!(direction.opposite(), direction.reflect('/').reflect('\\'));
+        }
+        assert_eq!(Dir4::North, Dir4::East.reflect('/'));
+        assert_eq!(Dir4::South, Dir4::East.reflect('\\'));
+        assert_eq!(Dir4::West, Dir4::North.reflect('\\'));
+        assert_eq!(IVec2::new(0, -1), Dir4::North.to_ivec2());
+    }
+
+    #[test]
+    fn test_dir8() {
+        for direction in Dir8::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction, direction.opposite().opposite());
+            assert_eq!(
+                IVec2::ZERO,
+                direction.to_ivec2() + direction.opposite().to_ivec2()
+            );
+        }
+        assert_eq!(Dir8::NorthWest, Dir8::North.turn_left());
+        assert_eq!(
+            Dir8::North.to_ivec2() + Dir8::East.to_ivec2(),
+            Dir8::NorthEast.to_ivec2()
+        );
+    }
+}
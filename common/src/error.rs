@@ -0,0 +1,152 @@
+use crate::span::{self, ErrorTree};
+use std::fmt::Display;
+use thiserror::Error;
+
+/// Why a day couldn't parse its input or solve a part of it.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AocError {
+    /// The input isn't what the day expects, at a 1-based line and column,
+    /// shown under the line with a caret.
+    #[error("{year} day {day}, line {line}, column {column}: {cause}\n  | {snippet}\n  | {caret:>column$}", caret = "^")]
+    Parse {
+        year: u16,
+        day: u8,
+        line: usize,
+        column: usize,
+        /// The whole line the error is on.
+        snippet: String,
+        cause: String,
+    },
+    /// The input parsed but makes no sense for the puzzle, such as a pipe
+    /// loop without a start.
+    #[error("{year} day {day}: {cause}")]
+    Solve { year: u16, day: u8, cause: String },
+}
+
+impl AocError {
+    /// A parse error at `at`, which must be a slice of `input` (or empty,
+    /// at its end) such as the input nom hands back with its errors.
+    pub fn parse(year: u16, day: u8, input: &str, at: &str, cause: impl Display) -> AocError {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(input.len());
+        AocError::at(year, day, input, offset, cause)
+    }
+
+    fn at(year: u16, day: u8, input: &str, offset: usize, cause: impl Display) -> AocError {
+        let start = input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+        let end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |newline| offset + newline);
+        AocError::Parse {
+            year,
+            day,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[start..offset].chars().count() + 1,
+            snippet: input[start..end].trim_end_matches('\r').to_string(),
+            cause: cause.to_string(),
+        }
+    }
+
+    /// A parse error where a nom parser gave up.
+    pub fn nom(
+        year: u16,
+        day: u8,
+        input: &str,
+        error: nom::Err<nom::error::Error<&str>>,
+    ) -> AocError {
+        match error {
+            nom::Err::Error(error) | nom::Err::Failure(error) => AocError::parse(
+                year,
+                day,
+                input,
+                error.input,
+                format!("expected {}", error.code.description()),
+            ),
+            nom::Err::Incomplete(_) => {
+                AocError::parse(year, day, input, "", "unexpected end of input")
+            }
+        }
+    }
+
+    /// A parse error where a nom parser over [`span::Span`]s gave up, at the
+    /// furthest point it got to and saying what it expected there.
+    pub fn located(year: u16, day: u8, input: &str, error: nom::Err<ErrorTree<'_>>) -> AocError {
+        match error {
+            nom::Err::Error(error) | nom::Err::Failure(error) => {
+                let (location, expected) = span::furthest(&error);
+                AocError::at(
+                    year,
+                    day,
+                    input,
+                    location.location_offset(),
+                    format!("expected {}", expected.join(" or ")),
+                )
+            }
+            nom::Err::Incomplete(_) => {
+                AocError::at(year, day, input, input.len(), "unexpected end of input")
+            }
+        }
+    }
+
+    pub fn solve(year: u16, day: u8, cause: impl Display) -> AocError {
+        AocError::Solve {
+            year,
+            day,
+            cause: cause.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{bytes::complete::tag, character::complete::digit1, sequence::preceded};
+
+    #[test]
+    fn test_parse() {
+        let input = "Game 1: 3 blue\nGame x: 4 red\n";
+        let at = &input[20..];
+        assert_eq!(
+            AocError::Parse {
+                year: 2023,
+                day: 2,
+                line: 2,
+                column: 6,
+                snippet: "Game x: 4 red".to_string(),
+                cause: "expected a number".to_string(),
+            },
+            AocError::parse(2023, 2, input, at, "expected a number")
+        );
+        assert_eq!(
+            "2023 day 2, line 2, column 6: expected a number\n  | Game x: 4 red\n  |      ^",
+            AocError::parse(2023, 2, input, at, "expected a number").to_string()
+        );
+
+        // anything that isn't part of the input points at its end
+        let AocError::Parse { line, column, .. } = AocError::parse(2023, 2, input, "elsewhere", "")
+        else {
+            panic!("should be a parse error");
+        };
+        assert_eq!((3, 1), (line, column));
+    }
+
+    #[test]
+    fn test_nom() {
+        let input = "Game 12\nGame x";
+        let line = input.lines().nth(1).expect("should have two lines");
+        let error = preceded(tag("Game "), digit1::<&str, nom::error::Error<&str>>)(line)
+            .expect_err("should not parse");
+        let AocError::Parse {
+            line,
+            column,
+            cause,
+            ..
+        } = AocError::nom(2023, 2, input, error)
+        else {
+            panic!("should be a parse error");
+        };
+        assert_eq!((2, 6, "expected Digit"), (line, column, cause.as_str()));
+    }
+}
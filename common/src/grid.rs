@@ -0,0 +1,291 @@
+//! A rectangular grid of tiles, as most puzzles that draw a map have.
+
+use crate::{Dir4, Dir8};
+use glam::IVec2;
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+/// Tiles stored row after row, at `(x, y)` positions with `y` growing down
+/// the page. Parse one with [`crate::Solution::grid`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of the given `cells`, row after row, `width` of them a row.
+    pub fn new(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            cells.len().is_multiple_of(width),
+            "{} cells don't make rows {width} wide",
+            cells.len()
+        );
+        Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    /// A grid with the tile at every position made by `tile`.
+    pub fn from_fn(width: usize, height: usize, mut tile: impl FnMut(IVec2) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| IVec2::new(x as i32, y as i32)))
+            .map(&mut tile)
+            .collect();
+        Grid::new(width, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The width and the height, as the position just past the last tile.
+    pub fn size(&self) -> IVec2 {
+        IVec2::new(self.width as i32, self.height as i32)
+    }
+
+    pub fn contains(&self, position: IVec2) -> bool {
+        (0..self.width as i32).contains(&position.x)
+            && (0..self.height as i32).contains(&position.y)
+    }
+
+    fn index_of(&self, position: IVec2) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.y as usize * self.width + position.x as usize)
+    }
+
+    fn position_of(&self, index: usize) -> IVec2 {
+        IVec2::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    /// The tile at `position`, none off the edge of the grid.
+    pub fn get(&self, position: IVec2) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: IVec2) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// The row `y`, from left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row, from the top.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// The column `x`, from the top.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + Clone {
+        assert!(
+            x < self.width,
+            "no column {x} in a grid {} wide",
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every column, from the left.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + Clone> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every tile and its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, tile)| (self.position_of(index), tile))
+    }
+
+    /// The positions next to `position` along a row or column, clockwise
+    /// from north, leaving out any off the edge of the grid.
+    pub fn neighbours4(&self, position: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .map(move |direction| position + direction.to_ivec2())
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The positions all around `position`, diagonals included, clockwise
+    /// from north, leaving out any off the edge of the grid.
+    pub fn neighbours8(&self, position: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .map(move |direction| position + direction.to_ivec2())
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The position of the first tile, row after row, that `predicate`
+    /// holds for.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<IVec2> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.position_of(index))
+    }
+
+    pub fn map<U>(&self, tile: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.cells.iter().map(tile).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, tile: T) -> Grid<T> {
+        Grid::new(width, vec![tile; width * height])
+    }
+
+    /// The grid mirrored along the diagonal from the top left, its rows
+    /// becoming columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |position| {
+            self[IVec2::new(position.y, position.x)].clone()
+        })
+    }
+
+    /// The grid turned a quarter clockwise, its left column becoming its top
+    /// row.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let bottom = self.height as i32 - 1;
+        Grid::from_fn(self.height, self.width, |position| {
+            self[IVec2::new(position.y, bottom - position.x)].clone()
+        })
+    }
+
+    /// The grid turned a quarter anticlockwise, its top row becoming its
+    /// left column.
+    pub fn rotate_anticlockwise(&self) -> Grid<T> {
+        let right = self.width as i32 - 1;
+        Grid::from_fn(self.height, self.width, |position| {
+            self[IVec2::new(right - position.y, position.x)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let right = self.width as i32 - 1;
+        Grid::from_fn(self.width, self.height, |position| {
+            self[IVec2::new(right - position.x, position.y)].clone()
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let bottom = self.height as i32 - 1;
+        Grid::from_fn(self.width, self.height, |position| {
+            self[IVec2::new(position.x, bottom - position.y)].clone()
+        })
+    }
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: IVec2) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position} is off a grid {} by {}", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    fn index_mut(&mut self, position: IVec2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position} is off a grid {width} by {height}"))
+    }
+}
+
+/// The grid as text again, a row a line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                write!(f, "{tile}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        let width = text.lines().next().map_or(0, |row| row.len());
+        Grid::new(width, text.lines().flat_map(str::chars).collect())
+    }
+
+    #[test]
+    fn test_get() {
+        let abc = grid("abc\ndef");
+        assert_eq!(IVec2::new(3, 2), abc.size());
+        assert_eq!(Some(&'f'), abc.get(IVec2::new(2, 1)));
+        assert_eq!('d', abc[IVec2::new(0, 1)]);
+        for off in [(-1, 0), (0, -1), (3, 0), (0, 2)] {
+            assert_eq!(None, abc.get(IVec2::from(off)), "{off:?}");
+        }
+        assert_eq!(Some(IVec2::new(1, 1)), abc.position(|tile| *tile == 'e'));
+
+        let mut abc = abc;
+        abc[IVec2::new(1, 0)] = 'B';
+        assert_eq!("aBc\ndef", abc.to_string());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let abc = grid("abc\ndef");
+        assert_eq!(
+            vec![&['a', 'b', 'c'][..], &['d', 'e', 'f']],
+            abc.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["ad", "be", "cf"],
+            abc.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some((IVec2::new(0, 1), &'d')), abc.iter().nth(3),);
+        assert_eq!(0, grid("").rows().count());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let abc = grid("abc\ndef");
+        let tiles = |positions: &mut dyn Iterator<Item = IVec2>| {
+            positions.map(|position| abc[position]).collect::<String>()
+        };
+        assert_eq!("bfd", tiles(&mut abc.neighbours4(IVec2::new(1, 1))));
+        assert_eq!("bd", tiles(&mut abc.neighbours4(IVec2::new(0, 0))));
+        assert_eq!("cfeda", tiles(&mut abc.neighbours8(IVec2::new(1, 0))));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let abc = grid("abc\ndef");
+        assert_eq!("ad\nbe\ncf", abc.transpose().to_string());
+        assert_eq!("da\neb\nfc", abc.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad", abc.rotate_anticlockwise().to_string());
+        assert_eq!("cba\nfed", abc.flip_horizontal().to_string());
+        assert_eq!("def\nabc", abc.flip_vertical().to_string());
+        assert_eq!(abc, abc.rotate_clockwise().rotate_anticlockwise());
+        assert_eq!(
+            abc.rotate_clockwise().rotate_clockwise(),
+            abc.flip_horizontal().flip_vertical()
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::Result;
+use std::{
+    borrow::Cow,
+    env, fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where the puzzle input for a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A path given on the command line.
+    File(PathBuf),
+    /// Whatever is piped into the process, or `-` on the command line.
+    Stdin,
+    /// The day's `input.txt` in the input cache.
+    Cache(PathBuf),
+}
+
+impl Source {
+    /// Picks the source for a day: an explicit path (`-` being stdin) first,
+    /// then stdin when something is piped into it, then the input cache.
+    pub fn resolve(path: Option<PathBuf>, stdin_piped: bool, year: u16, day: u8) -> Source {
+        match path {
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::File(path),
+            None if stdin_piped => Source::Stdin,
+            None => Source::Cache(cache_path(year, day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::Cache(path) if !path.exists() => Err(format!(
+                "no input at {}: pass a path, pipe it on stdin or save it there",
+                path.display()
+            )
+            .into()),
+            Source::Cache(path) => read_file(path),
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|error| format!("{}: {error}", path.display()).into())
+}
+
+/// The root of the input cache, `$AOC_CACHE_DIR` or the repository itself.
+pub fn cache_dir() -> PathBuf {
+    env::var_os("AOC_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+}
+
+/// The cached input of a day, `{cache}/{year}/day-{dd}/input.txt`.
+pub fn cache_path(year: u16, day: u8) -> PathBuf {
+    cache_path_in(&cache_dir(), year, day)
+}
+
+/// The cached input of a day below another cache root.
+pub fn cache_path_in(cache: &Path, year: u16, day: u8) -> PathBuf {
+    cache
+        .join(year.to_string())
+        .join(format!("day-{day:02}"))
+        .join("input.txt")
+}
+
+/// Reads the input of a day from `path`, stdin or the cache, in that order.
+///
+/// A stdin that is not a terminal but has nothing on it (`< /dev/null` under
+/// a scheduler or CI) falls through to the cache, unless `-` asked for it.
+pub fn read(year: u16, day: u8, path: Option<PathBuf>) -> Result<String> {
+    let explicit = path.is_some();
+    match Source::resolve(path, !io::stdin().is_terminal(), year, day) {
+        Source::Stdin if !explicit => match Source::Stdin.read()? {
+            input if input.is_empty() => Source::Cache(cache_path(year, day)).read(),
+            input => Ok(input),
+        },
+        source => source.read(),
+    }
+}
+
+/// Evens out the ways the same input can be saved: drops a byte order mark,
+/// CRLF line endings, and whitespace and control characters at the end of
+/// lines, and ends the input with exactly one newline. Leading whitespace is
+/// kept, as some inputs line things up with it.
+///
+/// Control characters go because a terminal can leave one behind when an
+/// input is copied out of it, such as the escape after the last game of
+/// 2023 day 2.
+pub fn normalise(input: &str) -> Cow<'_, str> {
+    let content = input.trim_end_matches(trailing);
+    let normal = !input.starts_with('\u{feff}')
+        && !input.contains('\r')
+        && input.len() == content.len() + 1
+        && input.ends_with('\n')
+        && content
+            .lines()
+            .all(|line| line.len() == line.trim_end_matches(trailing).len());
+    if normal {
+        return Cow::Borrowed(input);
+    }
+    let mut normalised = String::with_capacity(input.len() + 1);
+    for line in content.trim_start_matches('\u{feff}').lines() {
+        normalised.push_str(line.trim_end_matches(trailing));
+        normalised.push('\n');
+    }
+    Cow::Owned(normalised)
+}
+
+fn trailing(c: char) -> bool {
+    c.is_whitespace() || c.is_control()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let path = PathBuf::from("other.txt");
+        assert_eq!(
+            Source::File(path.clone()),
+            Source::resolve(Some(path), true, 2023, 1)
+        );
+        assert_eq!(Source::Stdin, Source::resolve(None, true, 2023, 1));
+        assert_eq!(
+            Source::Stdin,
+            Source::resolve(Some(PathBuf::from("-")), false, 2023, 1)
+        );
+        assert_eq!(
+            Source::Cache(cache_path(2023, 1)),
+            Source::resolve(None, false, 2023, 1)
+        );
+        assert!(cache_path(2023, 1).ends_with("2023/day-01/input.txt"));
+    }
+
+    #[test]
+    fn test_normalise() {
+        let input = "    [D]\n1 2\n\nmove 1\n";
+        assert!(matches!(normalise(input), Cow::Borrowed(_)));
+        assert_eq!(
+            input,
+            normalise("\u{feff}    [D]  \r\n1 2\r\n\r\nmove 1\t\r\n\r\n")
+        );
+        assert_eq!(input, normalise(input.trim_end()));
+        assert_eq!(input, normalise("    [D]\n1 2\n\nmove 1\x1b"));
+        assert_eq!("", normalise(""));
+    }
+
+    #[test]
+    fn test_missing_cache() {
+        let source = Source::Cache(PathBuf::from("2023/day-26/input.txt"));
+        let error = source.read().expect_err("should not exist");
+        assert!(error.to_string().contains("2023/day-26/input.txt"));
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+pub mod alloc;
+pub mod bitgrid;
+pub mod dir;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod render;
+pub mod span;
+pub mod sparse;
+pub mod trace;
+
+use alloc::Allocations;
+pub use bitgrid::{BitGrid, DirBitGrid};
+pub use dir::{Dir4, Dir8};
+pub use error::AocError;
+pub use grid::Grid;
+pub use sparse::SparseGrid;
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// A single day of Advent of Code.
+///
+/// The input is parsed once and both parts are solved from the same parsed
+/// value, as [`solve_day`] does, so neither part has to re-parse (or even
+/// see) the raw input.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Parsed<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1>;
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2>;
+
+    /// An error in the input at `at`, see [`AocError::parse`].
+    fn parse_error(input: &str, at: &str, cause: impl Display) -> AocError {
+        AocError::parse(Self::YEAR, Self::DAY, input, at, cause)
+    }
+
+    /// An error in the input where a nom parser gave up.
+    fn nom_error(input: &str, error: nom::Err<nom::error::Error<&str>>) -> AocError {
+        AocError::nom(Self::YEAR, Self::DAY, input, error)
+    }
+
+    /// An error in the input where a nom parser over spans gave up, see
+    /// [`AocError::located`].
+    fn located_error(input: &str, error: nom::Err<span::ErrorTree<'_>>) -> AocError {
+        AocError::located(Self::YEAR, Self::DAY, input, error)
+    }
+
+    /// The rows of a grid, which has to have some and every one of them as
+    /// long as the first. `grid` is `input` or a part of it, such as one of a
+    /// few grids between blank lines, so that errors point at the right line.
+    fn rows<'a>(input: &str, grid: &'a str) -> std::result::Result<Vec<&'a str>, AocError> {
+        let rows = grid.lines().collect::<Vec<_>>();
+        let width = match rows.first() {
+            Some(first) if !first.is_empty() => first.chars().count(),
+            _ => return Err(Self::parse_error(input, grid, "expected a grid")),
+        };
+        for row in &rows {
+            let length = row.chars().count();
+            if length != width {
+                // past the end of a short row, or on the first extra tile of a long one
+                let at = row
+                    .char_indices()
+                    .nth(width)
+                    .map_or(row.len(), |(at, _)| at);
+                return Err(Self::parse_error(
+                    input,
+                    &row[at..],
+                    format!("the grid is ragged, this row is {length} wide and the first {width}"),
+                ));
+            }
+        }
+        Ok(rows)
+    }
+
+    /// A grid of tiles, one a character, each made by `tile` or else an error
+    /// at that character saying what was expected there. `grid` is as for
+    /// [`Solution::rows`].
+    fn grid<T, E: Display>(
+        input: &str,
+        grid: &str,
+        mut tile: impl FnMut(char) -> std::result::Result<T, E>,
+    ) -> std::result::Result<Grid<T>, AocError> {
+        let rows = Self::rows(input, grid)?;
+        let width = rows[0].chars().count();
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for row in rows {
+            for (at, ch) in row.char_indices() {
+                cells.push(tile(ch).map_err(|cause| Self::parse_error(input, &row[at..], cause))?);
+            }
+        }
+        Ok(Grid::new(width, cells))
+    }
+
+    /// A grid of characters, each one of `tiles`.
+    fn char_grid(
+        input: &str,
+        grid: &str,
+        tiles: &str,
+    ) -> std::result::Result<Grid<char>, AocError> {
+        Self::grid(input, grid, |ch| match tiles.contains(ch) {
+            true => Ok(ch),
+            false => Err(format!("expected one of {tiles}")),
+        })
+    }
+
+    /// An input that parsed but can't be solved.
+    fn error(cause: impl Display) -> AocError {
+        AocError::solve(Self::YEAR, Self::DAY, cause)
+    }
+}
+
+/// How long parsing the input and solving a part from it took.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+    /// What the parse and the solve allocated together, when counting.
+    pub allocations: Allocations,
+}
+
+/// Parses `input` and solves the given part, rendering the answer as a string.
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<String> {
+    solve_timed::<S>(input, part).map(|(answer, _)| answer)
+}
+
+/// Like [`solve`], also timing the parse and the solve on their own.
+pub fn solve_timed<S: Solution>(input: &str, part: u8) -> Result<(String, Timings)> {
+    let allocations = Allocations::now();
+    let input = input::normalise(input);
+    let (parsed, parse) = parse_timed::<S>(&input)?;
+    let (answer, solve) = solve_part::<S>(&parsed, part)?;
+    Ok((
+        answer,
+        Timings {
+            parse,
+            solve,
+            allocations: Allocations::since(allocations),
+        },
+    ))
+}
+
+/// Parses `input` once and solves both parts from it, as running a whole
+/// day does. The parse is timed, and its allocations counted, with part 1
+/// only, so that adding up the parts adds it up once. An input that doesn't
+/// parse fails the day; a part that fails leaves the other to answer.
+pub fn solve_day<S: Solution>(input: &str) -> Result<[Result<(String, Timings)>; 2]> {
+    let mut allocations = Allocations::now();
+    let input = input::normalise(input);
+    let (parsed, mut parse) = parse_timed::<S>(&input)?;
+    Ok([1, 2].map(|part| {
+        let (answer, solve) = solve_part::<S>(&parsed, part)?;
+        let timings = Timings {
+            parse,
+            solve,
+            allocations: Allocations::since(allocations),
+        };
+        (parse, allocations) = (Duration::ZERO, Allocations::now());
+        Ok((answer, timings))
+    }))
+}
+
+fn parse_timed<S: Solution>(input: &str) -> Result<(S::Parsed<'_>, Duration)> {
+    let _span = tracing::info_span!("parse", year = S::YEAR, day = S::DAY).entered();
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    Ok((parsed, start.elapsed()))
+}
+
+fn solve_part<S: Solution>(parsed: &S::Parsed<'_>, part: u8) -> Result<(String, Duration)> {
+    let (year, day) = (S::YEAR, S::DAY);
+    let _span = tracing::info_span!("solve", year, day, part).entered();
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part1(parsed)?.to_string(),
+        2 => S::part2(parsed)?.to_string(),
+        _ => return Err(format!("{year} day {day} has no part {part}").into()),
+    };
+    Ok((answer, start.elapsed()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::IVec2;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Parsed<'a> = Vec<&'a str>;
+        type Part1 = usize;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Part1> {
+            Ok(parsed.len())
+        }
+
+        fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Part2> {
+            Ok(parsed
+                .iter()
+                .map(|line| line.parse::<u32>())
+                .sum::<std::result::Result<_, _>>()?)
+        }
+    }
+
+    #[test]
+    fn test_solve() -> Result<()> {
+        assert_eq!("3", solve::<Sum>("1\n2\n3", 1)?);
+        assert_eq!("6", solve::<Sum>("1\n2\n3", 2)?);
+        assert!(solve::<Sum>("1\n2\n3", 3).is_err());
+        assert!(solve::<Sum>("1\nx", 2).is_err());
+        assert_eq!("6", solve::<Sum>("\u{feff}1\r\n2 \r\n3\r\n\r\n", 2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_day() -> Result<()> {
+        let [part1, part2] = solve_day::<Sum>("1\n2\n3")?;
+        let (part1, part2) = (part1?, part2?);
+        assert_eq!(("3", "6"), (part1.0.as_str(), part2.0.as_str()));
+        // the parse is only timed once, with part 1
+        assert_eq!(Duration::ZERO, part2.1.parse);
+
+        // part 1 still answers when part 2 fails
+        let [part1, part2] = solve_day::<Sum>("1\nx")?;
+        assert_eq!("2", part1?.0);
+        assert!(part2.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rows() {
+        let input = "#.#\n...\n.#\n";
+        assert_eq!(
+            vec!["#.#", "..."],
+            Sum::rows(input, &input[..8]).expect("should be a grid")
+        );
+        let error = Sum::rows(input, input).expect_err("should be ragged");
+        assert_eq!(
+            "2000 day 1, line 3, column 3: the grid is ragged, this row is 2 wide and the first 3
+  | .#
+  |   ^",
+            error.to_string()
+        );
+        assert!(Sum::rows("", "").is_err());
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = "#.#\n.O.\n";
+        let grid = Sum::grid(input, input, |ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected # or ."),
+        });
+        let error = grid.expect_err("should not be a grid of # and .");
+        assert_eq!(
+            "2000 day 1, line 2, column 2: expected # or .\n  | .O.\n  |  ^",
+            error.to_string()
+        );
+
+        let grid = Sum::char_grid(input, input, "#.O").expect("should be a grid");
+        assert_eq!(IVec2::new(3, 2), grid.size());
+        assert_eq!(input.trim_end(), grid.to_string());
+    }
+}
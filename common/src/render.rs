@@ -0,0 +1,189 @@
+//! Drawing a grid for a terminal, with sets of its tiles picked out in
+//! colour, to see what a day is doing to its map.
+
+use crate::{BitGrid, Grid};
+use glam::IVec2;
+use std::fmt::{self, Display};
+
+/// The colours of the basic ANSI palette, leaving out black and white.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    /// The escape code drawing what follows in this colour.
+    fn code(self) -> &'static str {
+        match self {
+            Colour::Red => "\x1b[31m",
+            Colour::Green => "\x1b[32m",
+            Colour::Yellow => "\x1b[33m",
+            Colour::Blue => "\x1b[34m",
+            Colour::Magenta => "\x1b[35m",
+            Colour::Cyan => "\x1b[36m",
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// Some tiles to pick out, in a colour, as another glyph, or both.
+#[derive(Debug, Clone)]
+pub struct Layer<'a> {
+    cells: &'a BitGrid,
+    colour: Option<Colour>,
+    glyph: Option<char>,
+}
+
+impl<'a> Layer<'a> {
+    /// The tiles set in `cells`, drawn as they are until given a colour or
+    /// glyph.
+    pub fn new(cells: &'a BitGrid) -> Layer<'a> {
+        Layer {
+            cells,
+            colour: None,
+            glyph: None,
+        }
+    }
+
+    pub fn colour(self, colour: Colour) -> Layer<'a> {
+        Layer {
+            colour: Some(colour),
+            ..self
+        }
+    }
+
+    pub fn glyph(self, glyph: char) -> Layer<'a> {
+        Layer {
+            glyph: Some(glyph),
+            ..self
+        }
+    }
+}
+
+/// The line drawing character for the pipe bends and runs of 2023 day 10,
+/// `F-7|LJ`, and so for any map drawn with them.
+pub fn box_drawing(tile: char) -> char {
+    match tile {
+        'F' => '┌',
+        '7' => '┐',
+        'L' => '└',
+        'J' => '┘',
+        '|' => '│',
+        '-' => '─',
+        tile => tile,
+    }
+}
+
+/// A grid drawn a row a line with its layers over it, a later layer winning
+/// where they overlap. Log it, or [`Render::print`] it.
+#[derive(Debug, Clone)]
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    layers: Vec<Layer<'a>>,
+    box_drawing: bool,
+}
+
+impl<'a, T: Display> Render<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Render<'a, T> {
+        Render {
+            grid,
+            layers: vec![],
+            box_drawing: false,
+        }
+    }
+
+    pub fn layer(mut self, layer: Layer<'a>) -> Render<'a, T> {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Draws pipes with [`box_drawing`] characters.
+    pub fn box_drawing(self) -> Render<'a, T> {
+        Render {
+            box_drawing: true,
+            ..self
+        }
+    }
+
+    /// Writes the grid to stdout, ending with a newline.
+    pub fn print(&self) {
+        println!("{self}");
+    }
+
+    fn tile(&self, position: IVec2) -> (String, Option<Colour>) {
+        let mut text = self.grid[position].to_string();
+        if self.box_drawing {
+            text = text.chars().map(box_drawing).collect();
+        }
+        let mut colour = None;
+        for layer in self
+            .layers
+            .iter()
+            .filter(|layer| layer.cells.test(position))
+        {
+            if let Some(glyph) = layer.glyph {
+                text = glyph.to_string();
+            }
+            colour = layer.colour.or(colour);
+        }
+        (text, colour)
+    }
+}
+
+impl<T: Display> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.grid.height() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            // only switching colour where it changes keeps the text short
+            let mut current = None;
+            for x in 0..self.grid.width() {
+                let (text, colour) = self.tile(IVec2::new(x as i32, y as i32));
+                if colour != current {
+                    f.write_str(colour.map_or(RESET, Colour::code))?;
+                    current = colour;
+                }
+                f.write_str(&text)?;
+            }
+            if current.is_some() {
+                f.write_str(RESET)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::new(3, "F7.LJ.".chars().collect());
+        assert_eq!("F7.\nLJ.", Render::new(&grid).to_string());
+        assert_eq!("┌┐.\n└┘.", Render::new(&grid).box_drawing().to_string());
+
+        let mut pipes = BitGrid::new(3, 2);
+        let mut inside = BitGrid::new(3, 2);
+        for x in 0..2 {
+            pipes.set(IVec2::new(x, 0));
+        }
+        pipes.set(IVec2::new(1, 1));
+        inside.set(IVec2::new(2, 0));
+        inside.set(IVec2::new(1, 1));
+        let render = Render::new(&grid)
+            .layer(Layer::new(&pipes).colour(Colour::Blue))
+            .layer(Layer::new(&inside).colour(Colour::Green).glyph('I'));
+        assert_eq!(
+            "\x1b[34mF7\x1b[32mI\x1b[0m\nL\x1b[32mI\x1b[0m.",
+            render.to_string()
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! nom parsing over spans of the input that keep track of where they are, so
+//! a failed parse can say where it stopped and what it expected there.
+
+use nom_locate::LocatedSpan;
+use nom_supreme::error::{BaseErrorKind, GenericErrorTree, StackContext};
+
+/// A slice of the input that knows its offset, line and column.
+pub type Span<'a> = LocatedSpan<&'a str>;
+
+/// Everything a failed parse tried, with the contexts given to its parsers
+/// by `nom_supreme::ParserExt::context`.
+pub type ErrorTree<'a> = nom_supreme::error::ErrorTree<Span<'a>>;
+
+pub type IResult<'a, O> = nom::IResult<Span<'a>, O, ErrorTree<'a>>;
+
+/// The furthest a failed parse got, and everything that was expected there.
+///
+/// Within a stack of contexts the innermost one is what was expected, from
+/// where it started, as it names the thing better than the tag or character
+/// at the bottom of it.
+pub fn furthest<'a>(error: &ErrorTree<'a>) -> (Span<'a>, Vec<String>) {
+    match error {
+        GenericErrorTree::Base { location, kind } => {
+            let expected = match kind {
+                BaseErrorKind::Expected(expectation) => expectation.to_string(),
+                BaseErrorKind::Kind(kind) => kind.description().to_lowercase(),
+                BaseErrorKind::External(error) => error.to_string(),
+            };
+            (*location, vec![expected])
+        }
+        GenericErrorTree::Stack { base, contexts } => {
+            let context = contexts
+                .iter()
+                .find_map(|(location, context)| match context {
+                    StackContext::Context(context) => Some((*location, vec![context.to_string()])),
+                    StackContext::Kind(_) => None,
+                });
+            context.unwrap_or_else(|| furthest(base))
+        }
+        GenericErrorTree::Alt(alternatives) => {
+            let mut alternatives = alternatives.iter().map(furthest);
+            let first = alternatives.next().expect("alt always tries something");
+            alternatives.fold(
+                first,
+                |(location, mut expected), (other, others)| match other
+                    .location_offset()
+                    .cmp(&location.location_offset())
+                {
+                    std::cmp::Ordering::Greater => (other, others),
+                    std::cmp::Ordering::Less => (location, expected),
+                    std::cmp::Ordering::Equal => {
+                        for other in others {
+                            if !expected.contains(&other) {
+                                expected.push(other);
+                            }
+                        }
+                        (location, expected)
+                    }
+                },
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{branch::alt, character::complete, sequence::preceded};
+    use nom_supreme::{tag::complete::tag, ParserExt};
+
+    #[test]
+    fn test_furthest() {
+        fn colour(input: Span) -> IResult<Span> {
+            alt((tag("red"), tag("green"), tag("blue")))(input)
+        }
+        let mut cube = preceded(
+            complete::u32.context("a number of cubes"),
+            preceded(tag(" "), colour),
+        );
+
+        let nom::Err::Error(error) = cube(Span::new("3 pink")).expect_err("should not parse")
+        else {
+            panic!("should be a recoverable error");
+        };
+        let (location, expected) = furthest(&error);
+        assert_eq!((1, 3), (location.location_line(), location.get_column()));
+        assert_eq!(vec!["\"red\"", "\"green\"", "\"blue\""], expected);
+
+        let nom::Err::Error(error) = cube(Span::new("three red")).expect_err("should not parse")
+        else {
+            panic!("should be a recoverable error");
+        };
+        assert_eq!(vec!["a number of cubes"], furthest(&error).1);
+    }
+}
@@ -0,0 +1,179 @@
+//! A grid without edges, for puzzles whose maps grow as they run, such as
+//! falling sand or cellular automata.
+
+use glam::IVec2;
+use std::collections::HashMap;
+
+/// Tiles at any `(x, y)` positions, `y` growing down the page, keeping the
+/// box around every position ever set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    tiles: HashMap<IVec2, T>,
+    bounds: Option<(IVec2, IVec2)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            tiles: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Sets the tile at `position`, growing the bounds to take it in, and
+    /// hands back the one there before.
+    pub fn insert(&mut self, position: IVec2, tile: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(position), max.max(position)),
+            None => (position, position),
+        });
+        self.tiles.insert(position, tile)
+    }
+
+    /// Takes the tile at `position` away. The bounds don't shrink back, as
+    /// finding the new ones would mean going over every tile.
+    pub fn remove(&mut self, position: IVec2) -> Option<T> {
+        self.tiles.remove(&position)
+    }
+
+    pub fn get(&self, position: IVec2) -> Option<&T> {
+        self.tiles.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: IVec2) -> Option<&mut T> {
+        self.tiles.get_mut(&position)
+    }
+
+    pub fn contains(&self, position: IVec2) -> bool {
+        self.tiles.contains_key(&position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// The top left and bottom right corners, both included, of the box
+    /// around every position set so far, none before the first.
+    pub fn bounds(&self) -> Option<(IVec2, IVec2)> {
+        self.bounds
+    }
+
+    /// Every tile and its position, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.tiles.iter().map(|(position, tile)| (*position, tile))
+    }
+
+    /// The window from `min` to `max`, both included, as text, a row a line
+    /// and each position drawn by `tile`, given none where nothing is set.
+    pub fn render(&self, min: IVec2, max: IVec2, tile: impl Fn(Option<&T>) -> char) -> String {
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| tile(self.get(IVec2::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// The rows of the bounds, from the top, with `fill` wherever nothing is
+    /// set.
+    pub fn to_rows(&self, fill: T) -> Vec<Vec<T>> {
+        let Some((min, max)) = self.bounds else {
+            return vec![];
+        };
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| self.get(IVec2::new(x, y)).unwrap_or(&fill).clone())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(IVec2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (IVec2, T)>>(tiles: I) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        for (position, tile) in tiles {
+            grid.insert(position, tile);
+        }
+        grid
+    }
+}
+
+/// Rows from the top, the first tile of the first row at `(0, 0)`.
+impl<T> From<Vec<Vec<T>>> for SparseGrid<T> {
+    fn from(rows: Vec<Vec<T>>) -> SparseGrid<T> {
+        rows.into_iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .map(move |(x, tile)| (IVec2::new(x as i32, y as i32), tile))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+        assert_eq!(Vec::<Vec<char>>::new(), grid.to_rows('.'));
+        grid.insert(IVec2::new(2, 1), 'a');
+        grid.insert(IVec2::new(-1, 3), 'b');
+        assert_eq!(Some('a'), grid.insert(IVec2::new(2, 1), 'c'));
+        assert_eq!(Some((IVec2::new(-1, 1), IVec2::new(2, 3))), grid.bounds());
+        // removing a tile leaves the bounds as they were
+        assert_eq!(Some('b'), grid.remove(IVec2::new(-1, 3)));
+        assert_eq!(Some((IVec2::new(-1, 1), IVec2::new(2, 3))), grid.bounds());
+        assert_eq!(1, grid.len());
+    }
+
+    #[test]
+    fn test_render() {
+        let grid: SparseGrid<char> = [(IVec2::new(0, 0), '#'), (IVec2::new(-2, 1), '#')]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            "..#.\n#...",
+            grid.render(IVec2::new(-2, 0), IVec2::new(1, 1), |tile| {
+                tile.copied().unwrap_or('.')
+            })
+        );
+    }
+
+    #[test]
+    fn test_rows() {
+        let rows = vec![vec!['a', 'b'], vec!['c', 'd']];
+        let mut grid = SparseGrid::from(rows.clone());
+        assert_eq!(Some(&'c'), grid.get(IVec2::new(0, 1)));
+        assert_eq!(rows, grid.to_rows('.'));
+        grid.insert(IVec2::new(3, -1), 'e');
+        assert_eq!(
+            vec![
+                vec!['.', '.', '.', 'e'],
+                vec!['a', 'b', '.', '.'],
+                vec!['c', 'd', '.', '.']
+            ],
+            grid.to_rows('.')
+        );
+    }
+}
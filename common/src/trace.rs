@@ -0,0 +1,198 @@
+//! The tracing setup shared by `aoc` and the binaries of every day.
+
+use crate::Result;
+use std::{
+    env,
+    fs::File,
+    io,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use tracing::{level_filters::LevelFilter, Level};
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::{
+    filter::Directive, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer,
+};
+
+/// How many steps of each hot loop are logged when not told otherwise.
+pub const DEFAULT_STEPS: usize = 10;
+
+static STEPS: AtomicUsize = AtomicUsize::new(DEFAULT_STEPS);
+
+/// What to log, and where to.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Settings {
+    /// The most detailed level to print, or whatever `RUST_LOG` asks for
+    /// when unset.
+    pub level: Option<Level>,
+    /// More logs to print, each a [`directive`] such as `2023/16=debug`.
+    pub filters: Vec<String>,
+    /// How many steps of each hot loop to log, or [`DEFAULT_STEPS`].
+    pub steps: Option<usize>,
+    /// Where to write a chrome trace of every span, to open in
+    /// `chrome://tracing` or <https://ui.perfetto.dev>.
+    pub chrome: Option<PathBuf>,
+}
+
+impl Settings {
+    /// The settings in `$AOC_TRACE`, `$AOC_LOG` (comma separated),
+    /// `$AOC_STEPS` and `$AOC_CHROME_TRACE`, for the binaries without a
+    /// command line of their own.
+    pub fn from_env() -> Result<Settings> {
+        Ok(Settings {
+            level: env::var("AOC_TRACE")
+                .ok()
+                .map(|level| level.parse())
+                .transpose()?,
+            filters: env::var("AOC_LOG")
+                .map(|filters| filters.split(',').map(str::to_string).collect())
+                .unwrap_or_default(),
+            steps: env::var("AOC_STEPS")
+                .ok()
+                .map(|steps| steps.parse())
+                .transpose()?,
+            chrome: env::var_os("AOC_CHROME_TRACE").map(PathBuf::from),
+        })
+    }
+}
+
+/// Keeps the chrome trace open, which is only a complete file once this is
+/// dropped.
+#[must_use = "the chrome trace is only complete once this is dropped"]
+pub struct Guard {
+    _chrome: Option<FlushGuard>,
+}
+
+/// Logs to stderr, leaving stdout to the answers, and records a chrome trace
+/// when asked to. A subscriber set before, as by a test, is left alone.
+pub fn init(settings: Settings) -> Result<Guard> {
+    let filter = match settings.level {
+        Some(level) => EnvFilter::default().add_directive(LevelFilter::from_level(level).into()),
+        None => EnvFilter::from_default_env(),
+    };
+    let filter = settings
+        .filters
+        .iter()
+        .try_fold(filter, |filter, selector| {
+            Ok::<_, Box<dyn std::error::Error>>(filter.add_directive(directive(selector)?))
+        })?;
+    STEPS.store(settings.steps.unwrap_or(DEFAULT_STEPS), Ordering::Relaxed);
+    let fmt = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_filter(filter);
+    let (chrome, guard) = match settings.chrome {
+        Some(path) => {
+            let file =
+                File::create(&path).map_err(|error| format!("{}: {error}", path.display()))?;
+            let (chrome, guard) = ChromeLayerBuilder::new()
+                .writer(file)
+                .include_args(true)
+                .build();
+            (Some(chrome), Some(guard))
+        }
+        None => (None, None),
+    };
+    let _ = tracing_subscriber::registry()
+        .with(fmt)
+        .with(chrome)
+        .try_init();
+    Ok(Guard { _chrome: guard })
+}
+
+/// [`init`] with [`Settings::from_env`].
+pub fn init_from_env() -> Result<Guard> {
+    init(Settings::from_env()?)
+}
+
+/// The `EnvFilter` directive for the logs of a year, a day or a module of a
+/// day: `2023/16/part2=debug` is `y2023_day_16::part2=debug`, and `2022` is
+/// every level of every day of 2022. Anything not starting with a year is
+/// taken as a directive as it is, such as `aoc_common=info`.
+pub fn directive(selector: &str) -> Result<Directive> {
+    let (path, level) = match selector.split_once('=') {
+        Some((path, level)) => (path, Some(level)),
+        None => (selector, None),
+    };
+    let mut parts = path.split('/');
+    let directive = match parts.next().map(str::parse::<u16>) {
+        Some(Ok(year)) => {
+            let mut target = format!("y{year}");
+            if let Some(day) = parts.next() {
+                let day = day
+                    .parse::<u8>()
+                    .map_err(|_| format!("{selector}: {day} is not a day"))?;
+                target += &format!("_day_{day:02}");
+            }
+            for module in parts {
+                target += "::";
+                target += module;
+            }
+            match level {
+                Some(level) => format!("{target}={level}"),
+                None => target,
+            }
+        }
+        _ => selector.to_string(),
+    };
+    Ok(directive
+        .parse()
+        .map_err(|error| format!("{selector}: {error}"))?)
+}
+
+/// Counts the iterations of a hot loop so that its logs can stay in the code:
+/// only the first few steps, `--steps` of them, are logged.
+#[derive(Debug, Clone)]
+pub struct Steps {
+    taken: usize,
+    limit: usize,
+}
+
+impl Steps {
+    pub fn new() -> Steps {
+        Steps {
+            taken: 0,
+            limit: STEPS.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Takes a step, saying whether it is one of those to log.
+    pub fn step(&mut self) -> bool {
+        self.taken += 1;
+        self.taken <= self.limit
+    }
+}
+
+impl Default for Steps {
+    fn default() -> Steps {
+        Steps::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directive() -> Result<()> {
+        for (selector, expected) in [
+            ("2023/16/part2=debug", "y2023_day_16::part2=debug"),
+            ("2023/5", "y2023_day_05=trace"),
+            ("2022=info", "y2022=info"),
+            ("aoc_common=trace", "aoc_common=trace"),
+            ("warn", "warn"),
+        ] {
+            assert_eq!(expected, directive(selector)?.to_string(), "{selector}");
+        }
+        assert!(directive("2023/sixteen").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_steps() {
+        let mut steps = Steps { taken: 0, limit: 2 };
+        assert_eq!(
+            vec![true, true, false, false],
+            (0..4).map(|_| steps.step()).collect::<Vec<_>>()
+        );
+    }
+}